@@ -0,0 +1,95 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::{
+    input::{workspace_file, Source},
+    output::Format,
+};
+
+/// Command line arguments shared by every day's binary.
+#[derive(Debug, Parser)]
+#[command(about = "Solve both parts of an Advent of Code 2021 puzzle")]
+pub struct Args {
+    /// Puzzle input, `-` for stdin. Defaults to the day's `input.txt`, see
+    /// `AOC_INPUT_DIR`.
+    input: Option<PathBuf>,
+    /// Use the day's `<FIXTURE>_input.txt`, e.g. `mini` for `mini_input.txt`.
+    #[arg(short, long, conflicts_with = "input")]
+    fixture: Option<String>,
+    /// Use the day's `small_input.txt`, same as `--fixture small`.
+    #[arg(short, long, conflicts_with_all = ["input", "fixture"])]
+    small: bool,
+    /// How to print the answers.
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+}
+
+impl Args {
+    /// Parses the command line of the current process.
+    ///
+    /// Exits the process with a usage message if the arguments are invalid.
+    pub fn from_env() -> Self {
+        Self::parse()
+    }
+
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Where `day` reads its input from.
+    pub fn source(&self, day: u8) -> Source {
+        let fixture = self.fixture.as_deref().or(self.small.then_some("small"));
+        match (&self.input, fixture) {
+            (Some(path), _) => Source::from(path.as_os_str()),
+            (None, Some(name)) => Source::Path(workspace_file(day, &format!("{name}_input.txt"))),
+            (None, None) => Source::default_for(day),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::Args;
+    use crate::{
+        input::{workspace_file, Source},
+        output::Format,
+    };
+
+    fn source(args: &[&str]) -> Source {
+        Args::try_parse_from([&["day11"], args].concat())
+            .unwrap()
+            .source(11)
+    }
+
+    #[test]
+    fn sources_from_arguments() {
+        assert_eq!(source(&[]), Source::default_for(11));
+        assert_eq!(source(&["-"]), Source::Stdin);
+        assert_eq!(source(&["a.txt"]), Source::Path("a.txt".into()));
+        assert_eq!(
+            source(&["--small"]),
+            Source::Path(workspace_file(11, "small_input.txt"))
+        );
+        assert_eq!(
+            source(&["-f", "mini"]),
+            Source::Path(workspace_file(11, "mini_input.txt"))
+        );
+    }
+
+    #[test]
+    fn format_defaults_to_text() {
+        let args = Args::try_parse_from(["day11"]).unwrap();
+        assert_eq!(args.format(), Format::Text);
+        let args = Args::try_parse_from(["day11", "--format", "json", "-"]).unwrap();
+        assert_eq!(args.format(), Format::Json);
+    }
+
+    #[test]
+    fn input_and_fixture_conflict() {
+        assert!(Args::try_parse_from(["day11", "a.txt", "--small"]).is_err());
+        assert!(Args::try_parse_from(["day11", "-s", "-f", "mini"]).is_err());
+    }
+}
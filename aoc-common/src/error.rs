@@ -0,0 +1,205 @@
+use std::{
+    fmt::{self, Display},
+    io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use crate::parse::ParseError;
+
+/// The error type shared by all days.
+///
+/// Besides its [`ErrorKind`] an error may know the file, line and column it
+/// originated from. Lines and columns are 1-based.
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    file: Option<PathBuf>,
+    line: Option<usize>,
+    column: Option<usize>,
+}
+
+#[derive(Debug)]
+pub enum ErrorKind {
+    /// The input could not be read.
+    Io(io::Error),
+    /// The input is malformed.
+    Parse(ParseError),
+    /// The input is well-formed but has no answer.
+    Unsolvable(String),
+    /// The command line asks for something the input does not have.
+    Usage(String),
+}
+
+impl Error {
+    pub fn new(kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            file: None,
+            line: None,
+            column: None,
+        }
+    }
+
+    pub fn unsolvable(message: impl Display) -> Self {
+        Self::new(ErrorKind::Unsolvable(message.to_string()))
+    }
+
+    pub fn usage(message: impl Display) -> Self {
+        Self::new(ErrorKind::Usage(message.to_string()))
+    }
+
+    /// Attaches the file the error originated from, unless it already has one.
+    pub fn in_file(mut self, file: impl AsRef<Path>) -> Self {
+        self.file.get_or_insert_with(|| file.as_ref().to_path_buf());
+        self
+    }
+
+    /// Attaches a line number, unless the error already has one.
+    ///
+    /// Parse errors always report their own position.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Attaches a line and column number, unless the error already has them.
+    pub fn at(self, line: usize, column: usize) -> Self {
+        let mut error = self.at_line(line);
+        error.column.get_or_insert(column);
+        error
+    }
+
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    pub fn file(&self) -> Option<&Path> {
+        self.file.as_deref()
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        match &self.kind {
+            ErrorKind::Parse(e) => Some(e.line()),
+            _ => self.line,
+        }
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        match &self.kind {
+            ErrorKind::Parse(e) => Some(e.column()),
+            _ => self.column,
+        }
+    }
+
+    /// Process exit code for this error, following the BSD `sysexits.h`
+    /// convention: 66 if the input could not be read, 65 if it is malformed,
+    /// 64 if the command line does not fit it and 1 if it has no solution.
+    pub fn exit_code(&self) -> ExitCode {
+        match self.kind {
+            ErrorKind::Io(_) => ExitCode::from(66),
+            ErrorKind::Parse(_) => ExitCode::from(65),
+            ErrorKind::Usage(_) => ExitCode::from(64),
+            ErrorKind::Unsolvable(_) => ExitCode::FAILURE,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Mimic the `file:line:column: message` format of compilers, so
+        // editors can jump to the location.
+        let mut location = Vec::new();
+        if let Some(file) = &self.file {
+            location.push(file.display().to_string());
+        }
+        if let Some(line) = self.line() {
+            location.push(line.to_string());
+        }
+        if let Some(column) = self.column() {
+            location.push(column.to_string());
+        }
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(":"))?;
+        }
+
+        match &self.kind {
+            ErrorKind::Io(e) => write!(f, "{e}"),
+            ErrorKind::Parse(e) => {
+                write!(f, "invalid input: ")?;
+                e.describe(f)
+            }
+            ErrorKind::Unsolvable(message) => write!(f, "no solution: {message}"),
+            ErrorKind::Usage(message) => write!(f, "invalid usage: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ErrorKind::Io(e) => Some(e),
+            ErrorKind::Parse(e) => Some(e),
+            ErrorKind::Unsolvable(_) | ErrorKind::Usage(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Self::new(ErrorKind::Io(e))
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Self::new(ErrorKind::Parse(e))
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, ErrorKind};
+    use crate::parse::ParseError;
+
+    #[test]
+    fn display_without_location() {
+        let error = Error::unsolvable("no pattern for 1");
+        assert_eq!(error.to_string(), "no solution: no pattern for 1");
+    }
+
+    #[test]
+    fn display_with_location() {
+        let error = Error::from(ParseError::unexpected_end("an end point", 7).offset(2, 0))
+            .in_file("day05/input.txt");
+        assert_eq!(
+            error.to_string(),
+            "day05/input.txt:3:7: invalid input: expected an end point, found end of input"
+        );
+    }
+
+    #[test]
+    fn parse_errors_keep_their_position() {
+        let error = Error::from(ParseError::unexpected_token("x", "a digit", 4)).at(9, 9);
+        assert_eq!((error.line(), error.column()), (Some(1), Some(4)));
+        assert!(matches!(
+            error.kind(),
+            ErrorKind::Parse(ParseError::UnexpectedToken { .. })
+        ));
+    }
+
+    #[test]
+    fn innermost_location_wins() {
+        let error = Error::unsolvable("no pattern for 1")
+            .at_line(2)
+            .at_line(5)
+            .in_file("a.txt")
+            .in_file("b.txt");
+        assert_eq!(error.line(), Some(2));
+        assert_eq!(error.column(), None);
+        assert_eq!(error.file().and_then(|p| p.to_str()), Some("a.txt"));
+        assert!(matches!(error.kind(), ErrorKind::Unsolvable(_)));
+    }
+}
@@ -0,0 +1,159 @@
+use std::{
+    env,
+    ffi::OsStr,
+    fmt::{self, Display},
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::PathBuf,
+};
+
+use crate::{Error, Result};
+
+/// Environment variable naming a directory with one `dayNN.txt` input per day.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where to read a puzzle input from.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// The default input of `day`, see [`default_path`].
+    pub fn default_for(day: u8) -> Self {
+        Source::Path(default_path(day))
+    }
+
+    /// Reads the whole input and normalises it, see [`normalize`].
+    pub fn load(&self) -> Result<String> {
+        Ok(normalize(&self.read()?))
+    }
+
+    /// Reads the whole input as is.
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Source::Path(path) => {
+                fs::read_to_string(path).map_err(|e| Error::from(e).in_file(path))
+            }
+            Source::Stdin => {
+                let mut content = String::new();
+                io::stdin().read_to_string(&mut content)?;
+                Ok(content)
+            }
+        }
+    }
+
+    /// Opens the input for reading it piece by piece, without normalising it.
+    pub fn open(&self) -> Result<Box<dyn BufRead>> {
+        match self {
+            Source::Path(path) => {
+                let file = File::open(path).map_err(|e| Error::from(e).in_file(path))?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+
+    /// Attaches this source to errors that do not know their file yet.
+    pub fn annotate(&self, error: Error) -> Error {
+        match self {
+            Source::Path(path) => error.in_file(path),
+            Source::Stdin => error.in_file("<stdin>"),
+        }
+    }
+}
+
+/// `-` stands for stdin, anything else is a path.
+impl From<&OsStr> for Source {
+    fn from(s: &OsStr) -> Self {
+        if s == "-" {
+            Source::Stdin
+        } else {
+            Source::Path(PathBuf::from(s))
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Path(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+/// Path of the puzzle input of `day`.
+///
+/// This is `$AOC_INPUT_DIR/dayNN.txt` if the variable is set, and the day's
+/// `input.txt` in this workspace otherwise.
+pub fn default_path(day: u8) -> PathBuf {
+    match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir).join(format!("day{day:02}.txt")),
+        None => workspace_file(day, "input.txt"),
+    }
+}
+
+/// Path of `name` in the directory of `day` in this workspace.
+pub fn workspace_file(day: u8, name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{day:02}"))
+        .join(name)
+}
+
+/// Converts CRLF line endings to LF and strips trailing whitespace from every
+/// line and from the end of the input.
+pub fn normalize(s: &str) -> String {
+    s.lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_end()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsStr;
+
+    use super::{normalize, Source};
+
+    #[test]
+    fn normalize_line_endings_and_whitespace() {
+        let actual = normalize("a b \r\nc\t\r\n\r\nd\r\n\r\n  \n");
+        assert_eq!(actual, "a b\nc\n\nd");
+    }
+
+    #[test]
+    fn normalize_keeps_leading_whitespace() {
+        let actual = normalize(" 8  2 23\n21  9 14\n");
+        assert_eq!(actual, " 8  2 23\n21  9 14");
+    }
+
+    #[test]
+    fn source_from_os_str() {
+        assert_eq!(Source::from(OsStr::new("-")), Source::Stdin);
+        assert_eq!(
+            Source::from(OsStr::new("input.txt")),
+            Source::Path("input.txt".into())
+        );
+    }
+
+    #[test]
+    fn load_missing_file_names_path() {
+        let error = Source::Path("does/not/exist.txt".into())
+            .load()
+            .unwrap_err();
+        assert!(error.to_string().starts_with("does/not/exist.txt: "));
+    }
+
+    #[test]
+    fn load_workspace_input() {
+        let actual = Source::Path(super::workspace_file(6, "small_input.txt"))
+            .load()
+            .unwrap();
+        assert_eq!(actual, "3,4,3,1,2");
+    }
+}
@@ -0,0 +1,185 @@
+//! Shared building blocks for the Advent of Code 2021 solutions.
+
+use std::{fmt::Display, process::ExitCode, time::Instant};
+
+pub mod cli;
+mod error;
+pub mod input;
+pub mod output;
+pub mod parse;
+
+pub use error::{Error, ErrorKind, Result};
+use input::Source;
+use output::{Format, Record};
+pub use parse::ParseError;
+
+/// One of the two puzzles of a day.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+/// A puzzle solution for a single day.
+///
+/// The input is parsed once into a typed model which is then shared by both
+/// parts, so parts must not rely on state left behind by the other.
+pub trait Solution {
+    /// Day of the advent calendar, `1..=25`.
+    const DAY: u8;
+    /// Day 25 only has a single puzzle.
+    const HAS_PART_TWO: bool = true;
+
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne>;
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo>;
+}
+
+/// Parses `input` and solves `part`, rendering the answer as a string.
+///
+/// Returns `None` if the solution has no such part.
+pub fn solve<S: Solution>(input: &str, part: Part) -> Option<Result<String>> {
+    if part == Part::Two && !S::HAS_PART_TWO {
+        return None;
+    }
+
+    Some(S::parse(input).and_then(|model| match part {
+        Part::One => S::part_one(&model).map(|answer| answer.to_string()),
+        Part::Two => S::part_two(&model).map(|answer| answer.to_string()),
+    }))
+}
+
+/// Solves both parts for the input from `source` and prints the answers in
+/// `format`.
+///
+/// Returns the parsed input for binaries that print more than the answers.
+pub fn run<S: Solution>(source: &Source, format: Format) -> Result<S::Input> {
+    let raw = source.read()?;
+    let start = Instant::now();
+    let input = S::parse(&input::normalize(&raw)).map_err(|e| source.annotate(e))?;
+    let parsing = start.elapsed();
+
+    let hash = match format {
+        Format::Text => String::new(),
+        Format::Json => output::sha256_hex(raw.as_bytes()),
+    };
+    let print = |part: Part, solve: &dyn Fn() -> Result<String>| -> Result<()> {
+        let start = Instant::now();
+        let answer = solve().map_err(|e| source.annotate(e))?;
+        match format {
+            Format::Text => match part {
+                Part::One => println!("Part One: {answer}"),
+                Part::Two => println!("Part Two: {answer}"),
+            },
+            Format::Json => {
+                let elapsed = parsing + start.elapsed();
+                println!(
+                    "{}",
+                    Record::new(S::DAY, part, answer, &hash, elapsed).to_json()
+                );
+            }
+        }
+        Ok(())
+    };
+    print(Part::One, &|| S::part_one(&input).map(|a| a.to_string()))?;
+    if S::HAS_PART_TWO {
+        print(Part::Two, &|| S::part_two(&input).map(|a| a.to_string()))?;
+    }
+
+    Ok(input)
+}
+
+/// Prints `error` on stderr and returns its exit code.
+pub fn report(error: Error) -> ExitCode {
+    eprintln!("error: {error}");
+    error.exit_code()
+}
+
+/// Entry point of a day's binary.
+///
+/// Runs the day on the input selected by the command line, see
+/// [`cli::Args`], and
+/// reports errors on stderr instead of panicking.
+pub fn main<S: Solution>() -> ExitCode {
+    let args = cli::Args::from_env();
+    match run::<S>(&args.source(S::DAY), args.format()) {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(e) => report(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, solve, Error, Part, Result, Solution};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+        const HAS_PART_TWO: bool = false;
+
+        type Input = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = std::convert::Infallible;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            parse::fields(input, ",")
+                .map(|(column, field)| parse::number(field, column))
+                .collect::<std::result::Result<_, _>>()
+                .map_err(Error::from)
+        }
+
+        fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+            Ok(input.iter().sum())
+        }
+
+        fn part_two(_: &Self::Input) -> Result<Self::PartTwo> {
+            Err(Error::unsolvable("no part two"))
+        }
+    }
+
+    #[test]
+    fn solve_renders_answer() {
+        let actual = solve::<Sum>("1,2,3", Part::One).unwrap().unwrap();
+        assert_eq!(actual, "6");
+    }
+
+    #[test]
+    fn solve_reports_parse_errors() {
+        let actual = solve::<Sum>("1,x,3", Part::One).unwrap();
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn solve_skips_missing_part_two() {
+        assert!(solve::<Sum>("1,2,3", Part::Two).is_none());
+    }
+
+    #[test]
+    fn part_from_number() {
+        assert_eq!(Part::from_number(1), Some(Part::One));
+        assert_eq!(Part::from_number(2), Some(Part::Two));
+        assert_eq!(Part::from_number(3), None);
+    }
+}
@@ -0,0 +1,124 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::Part;
+
+/// How answers are printed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, ValueEnum)]
+pub enum Format {
+    /// One `Part One: <answer>` line per part.
+    #[default]
+    Text,
+    /// One JSON object per part and line, see [`Record`].
+    Json,
+}
+
+/// A single answer in machine readable form.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    /// SHA-256 of the input file as read, before normalisation.
+    pub input_sha256: String,
+    /// Time spent parsing the input and solving the part.
+    pub elapsed_ns: u64,
+}
+
+impl Record {
+    pub fn new(day: u8, part: Part, answer: String, input_sha256: &str, elapsed: Duration) -> Self {
+        Self {
+            day,
+            part: part.number(),
+            answer,
+            input_sha256: input_sha256.to_string(),
+            elapsed_ns: elapsed.as_nanos().try_into().unwrap_or(u64::MAX),
+        }
+    }
+
+    /// The record as a single line of JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("records always serialize")
+    }
+}
+
+/// Lowercase hex SHA-256 digest of `content`.
+pub fn sha256_hex(content: &[u8]) -> String {
+    Sha256::digest(content)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Renders `rows` as a plain text table, every column left-aligned and as
+/// wide as its widest cell, with two spaces between columns.
+pub fn render_table<R: AsRef<[String]>>(rows: &[R]) -> String {
+    let mut widths: Vec<usize> = Vec::new();
+    for row in rows {
+        for (column, cell) in row.as_ref().iter().enumerate() {
+            let width = cell.chars().count();
+            match widths.get_mut(column) {
+                Some(max) => *max = (*max).max(width),
+                None => widths.push(width),
+            }
+        }
+    }
+    rows.iter()
+        .map(|row| {
+            row.as_ref()
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{render_table, sha256_hex, Record};
+    use crate::Part;
+
+    #[test]
+    fn sha256_of_empty_input() {
+        assert_eq!(
+            sha256_hex(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn record_to_json() {
+        let record = Record::new(
+            6,
+            Part::Two,
+            "26".to_string(),
+            "abc",
+            Duration::from_micros(2),
+        );
+        assert_eq!(
+            record.to_json(),
+            r#"{"day":6,"part":2,"answer":"26","input_sha256":"abc","elapsed_ns":2000}"#
+        );
+    }
+
+    #[test]
+    fn tables_pad_every_column_but_the_last() {
+        let rows = [
+            vec!["Day".to_string(), "Answer".to_string()],
+            vec!["1".to_string(), "1502".to_string()],
+            vec!["25".to_string(), String::new()],
+        ];
+        assert_eq!(render_table(&rows), "Day  Answer\n1    1502\n25");
+        assert_eq!(render_table::<Vec<String>>(&[]), "");
+    }
+}
@@ -0,0 +1,261 @@
+use std::{
+    fmt::{self, Display},
+    num::{IntErrorKind, ParseIntError},
+    str::FromStr,
+};
+
+/// A malformed puzzle input.
+///
+/// Every variant knows the 1-based line and column it occurred at. Parsers of
+/// a single line report line 1, [`ParseError::offset`] moves the error to its
+/// actual position in the input.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseError {
+    /// Found `token` where `expected` was required.
+    UnexpectedToken {
+        token: String,
+        expected: &'static str,
+        line: usize,
+        column: usize,
+    },
+    /// The input ended where `expected` was required.
+    UnexpectedEnd {
+        expected: &'static str,
+        line: usize,
+        column: usize,
+    },
+    /// A line has `found` fields instead of `expected`.
+    WrongFieldCount {
+        expected: usize,
+        found: usize,
+        line: usize,
+        column: usize,
+    },
+    /// `token` is a number that does not fit the target type.
+    NumericOverflow {
+        token: String,
+        line: usize,
+        column: usize,
+    },
+}
+
+impl ParseError {
+    pub fn unexpected_token(token: impl Display, expected: &'static str, column: usize) -> Self {
+        ParseError::UnexpectedToken {
+            token: token.to_string(),
+            expected,
+            line: 1,
+            column,
+        }
+    }
+
+    pub fn unexpected_end(expected: &'static str, column: usize) -> Self {
+        ParseError::UnexpectedEnd {
+            expected,
+            line: 1,
+            column,
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        self.position().0
+    }
+
+    pub fn column(&self) -> usize {
+        self.position().1
+    }
+
+    /// Moves the error `lines` lines down and, if it is on the first line,
+    /// `columns` columns to the right.
+    pub fn offset(mut self, lines: usize, columns: usize) -> Self {
+        let (line, column) = self.position_mut();
+        if *line == 1 {
+            *column += columns;
+        }
+        *line += lines;
+        self
+    }
+
+    fn position(&self) -> (usize, usize) {
+        match self {
+            ParseError::UnexpectedToken { line, column, .. }
+            | ParseError::UnexpectedEnd { line, column, .. }
+            | ParseError::WrongFieldCount { line, column, .. }
+            | ParseError::NumericOverflow { line, column, .. } => (*line, *column),
+        }
+    }
+
+    fn position_mut(&mut self) -> (&mut usize, &mut usize) {
+        match self {
+            ParseError::UnexpectedToken { line, column, .. }
+            | ParseError::UnexpectedEnd { line, column, .. }
+            | ParseError::WrongFieldCount { line, column, .. }
+            | ParseError::NumericOverflow { line, column, .. } => (line, column),
+        }
+    }
+
+    /// Writes the error without its position.
+    pub(crate) fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedToken {
+                token, expected, ..
+            } => write!(f, "expected {expected}, found `{token}`"),
+            ParseError::UnexpectedEnd { expected, .. } => {
+                write!(f, "expected {expected}, found end of input")
+            }
+            ParseError::WrongFieldCount {
+                expected, found, ..
+            } => write!(f, "expected {expected} fields, found {found}"),
+            ParseError::NumericOverflow { token, .. } => write!(f, "number `{token}` is too large"),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: ", self.line(), self.column())?;
+        self.describe(f)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses the integer `token` starting at `column` of the first line.
+pub fn number<T>(token: &str, column: usize) -> Result<T, ParseError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    token.parse().map_err(|e: ParseIntError| match e.kind() {
+        IntErrorKind::Empty => ParseError::unexpected_end("a number", column),
+        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => ParseError::NumericOverflow {
+            token: token.to_string(),
+            line: 1,
+            column,
+        },
+        _ => ParseError::unexpected_token(token, "a number", column),
+    })
+}
+
+/// Splits `s` at `separator`, pairing every field with its 1-based column.
+pub fn fields<'a>(s: &'a str, separator: &'a str) -> impl Iterator<Item = (usize, &'a str)> {
+    s.split(separator)
+        .map(move |field| (column_of(s, field), field))
+}
+
+/// Splits `s` at whitespace, pairing every word with its 1-based column.
+pub fn words(s: &str) -> impl Iterator<Item = (usize, &str)> {
+    s.split_whitespace()
+        .map(move |word| (column_of(s, word), word))
+}
+
+/// Like [`fields`], but fails unless there are exactly `N` fields.
+pub fn exact_fields<'a, const N: usize>(
+    s: &'a str,
+    separator: &'a str,
+) -> Result<[(usize, &'a str); N], ParseError> {
+    exactly(s, fields(s, separator))
+}
+
+/// Like [`words`], but fails unless there are exactly `N` words.
+pub fn exact_words<const N: usize>(s: &str) -> Result<[(usize, &str); N], ParseError> {
+    exactly(s, words(s))
+}
+
+fn exactly<'a, const N: usize>(
+    s: &str,
+    items: impl Iterator<Item = (usize, &'a str)>,
+) -> Result<[(usize, &'a str); N], ParseError> {
+    let items: Vec<_> = items.collect();
+    let found = items.len();
+    items.try_into().map_err(|items: Vec<_>| {
+        // Point at the first surplus field, or at the end of a short line.
+        let column = items.get(N).map_or(s.len() + 1, |&(column, _)| column);
+        ParseError::WrongFieldCount {
+            expected: N,
+            found,
+            line: 1,
+            column,
+        }
+    })
+}
+
+/// Parses every line of `s` with `f`, moving errors to their line.
+pub fn parse_lines<T>(
+    s: &str,
+    mut f: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    s.lines()
+        .enumerate()
+        .map(|(idx, line)| f(line).map_err(|e| e.offset(idx, 0)))
+        .collect()
+}
+
+fn column_of(s: &str, part: &str) -> usize {
+    part.as_ptr() as usize - s.as_ptr() as usize + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{exact_fields, exact_words, number, parse_lines, words, ParseError};
+
+    #[test]
+    fn number_classifies_errors() {
+        assert_eq!(number::<u8>("42", 1), Ok(42));
+        assert_eq!(
+            number::<u8>("256", 3),
+            Err(ParseError::NumericOverflow {
+                token: "256".to_string(),
+                line: 1,
+                column: 3
+            })
+        );
+        assert_eq!(
+            number::<u8>("x", 2),
+            Err(ParseError::unexpected_token("x", "a number", 2))
+        );
+        assert_eq!(
+            number::<u8>("", 5),
+            Err(ParseError::unexpected_end("a number", 5))
+        );
+    }
+
+    #[test]
+    fn words_know_their_columns() {
+        let actual: Vec<_> = words("  ab c   d").collect();
+        assert_eq!(actual, vec![(3, "ab"), (6, "c"), (10, "d")]);
+    }
+
+    #[test]
+    fn exact_fields_counts_fields() {
+        assert_eq!(exact_fields::<2>("1,2", ","), Ok([(1, "1"), (3, "2")]));
+        assert_eq!(
+            exact_fields::<2>("1,2,3", ","),
+            Err(ParseError::WrongFieldCount {
+                expected: 2,
+                found: 3,
+                line: 1,
+                column: 5
+            })
+        );
+        assert_eq!(exact_words::<2>("up").unwrap_err().column(), 3);
+    }
+
+    #[test]
+    fn parse_lines_moves_errors() {
+        let actual = parse_lines("1\n2\n 300", |line| {
+            let [(column, word)] = exact_words(line)?;
+            number::<u8>(word, column)
+        });
+        let error = actual.unwrap_err();
+        assert_eq!((error.line(), error.column()), (3, 2));
+        assert_eq!(error.to_string(), "3:2: number `300` is too large");
+    }
+
+    #[test]
+    fn offset_only_shifts_columns_of_first_line() {
+        let error = ParseError::unexpected_end("a board", 4).offset(2, 10);
+        assert_eq!((error.line(), error.column()), (3, 14));
+        let error = error.offset(1, 10);
+        assert_eq!((error.line(), error.column()), (4, 14));
+    }
+}
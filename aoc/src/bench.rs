@@ -0,0 +1,220 @@
+use std::{
+    fs, io,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{output::render_table, Error, Result, Solution};
+use serde::{Deserialize, Serialize};
+
+/// Inputs every day is benchmarked on, looked up in the day's directory.
+pub const INPUTS: [&str; 2] = ["small_input.txt", "input.txt"];
+
+/// Slowdowns below this are noise and never count as a regression.
+const NOISE_FLOOR: Duration = Duration::from_micros(10);
+
+/// Fastest time of each stage of a solution.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Option<Duration>,
+}
+
+/// Parses `input` and solves both parts `runs` times, keeping the fastest
+/// time of each stage.
+pub fn time<S: Solution>(input: &str, runs: u32) -> Result<Timings> {
+    let (parse, model) = fastest(runs, || S::parse(input))?;
+    let (part_one, _) = fastest(runs, || S::part_one(&model))?;
+    let part_two = if S::HAS_PART_TWO {
+        Some(fastest(runs, || S::part_two(&model))?.0)
+    } else {
+        None
+    };
+
+    Ok(Timings {
+        parse,
+        part_one,
+        part_two,
+    })
+}
+
+fn fastest<T>(runs: u32, mut f: impl FnMut() -> Result<T>) -> Result<(Duration, T)> {
+    let mut best = Duration::MAX;
+    let mut output = None;
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let value = f()?;
+        best = best.min(start.elapsed());
+        output = Some(value);
+    }
+
+    Ok((best, output.expect("ran at least once")))
+}
+
+/// One row of a benchmark run, as stored in a baseline file.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Record {
+    pub day: u8,
+    pub input: String,
+    pub parse_ns: u64,
+    pub part_one_ns: u64,
+    pub part_two_ns: Option<u64>,
+}
+
+impl Record {
+    pub fn new(day: u8, input: &str, timings: Timings) -> Self {
+        Self {
+            day,
+            input: input.to_string(),
+            parse_ns: nanos(timings.parse),
+            part_one_ns: nanos(timings.part_one),
+            part_two_ns: timings.part_two.map(nanos),
+        }
+    }
+
+    fn stages(&self) -> [Option<u64>; 3] {
+        [
+            Some(self.parse_ns),
+            Some(self.part_one_ns),
+            self.part_two_ns,
+        ]
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+/// The results of a whole benchmark run.
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub records: Vec<Record>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path).map_err(|e| Error::from(e).in_file(path))?;
+        serde_json::from_str(&content).map_err(|e| Error::from(io::Error::from(e)).in_file(path))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::from)?;
+        fs::write(path, json + "\n").map_err(|e| Error::from(e).in_file(path))
+    }
+
+    fn find(&self, day: u8, input: &str) -> Option<&Record> {
+        self.records
+            .iter()
+            .find(|record| record.day == day && record.input == input)
+    }
+}
+
+/// Renders `current` as a table, comparing it against `baseline`.
+///
+/// Stages that got more than `threshold` percent slower are marked with their
+/// slowdown. Returns the table and the number of regressions.
+pub fn table(current: &Baseline, baseline: Option<&Baseline>, threshold: f64) -> (String, usize) {
+    let mut rows = vec![[
+        "Day".to_string(),
+        "Input".to_string(),
+        "Parse".to_string(),
+        "Part 1".to_string(),
+        "Part 2".to_string(),
+    ]];
+    let mut regressions = 0;
+    for record in &current.records {
+        let previous = baseline.and_then(|b| b.find(record.day, &record.input));
+        let mut row = [
+            format!("{:02}", record.day),
+            record.input.clone(),
+            String::new(),
+            String::new(),
+            String::new(),
+        ];
+        for (idx, stage) in record.stages().into_iter().enumerate() {
+            let Some(ns) = stage else {
+                row[idx + 2] = "-".to_string();
+                continue;
+            };
+            row[idx + 2] = format!("{:.1?}", Duration::from_nanos(ns));
+            let before = previous.and_then(|p| p.stages()[idx]);
+            if let Some(change) = before.and_then(|before| regression(before, ns, threshold)) {
+                row[idx + 2] += &format!(" (+{change:.0}%)");
+                regressions += 1;
+            }
+        }
+        rows.push(row);
+    }
+
+    (render_table(&rows), regressions)
+}
+
+/// Slowdown from `before` to `after` in percent, if it exceeds `threshold`.
+fn regression(before: u64, after: u64, threshold: f64) -> Option<f64> {
+    if after.saturating_sub(before) < nanos(NOISE_FLOOR) {
+        return None;
+    }
+    let change = (after as f64 / before.max(1) as f64 - 1.0) * 100.0;
+    (change > threshold).then_some(change)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{regression, table, time, Baseline, Record, Timings};
+
+    fn record(day: u8, parse_ns: u64, part_two_ns: Option<u64>) -> Record {
+        Record::new(
+            day,
+            "input.txt",
+            Timings {
+                parse: Duration::from_nanos(parse_ns),
+                part_one: Duration::from_micros(5),
+                part_two: part_two_ns.map(Duration::from_nanos),
+            },
+        )
+    }
+
+    #[test]
+    fn time_skips_missing_part_two() {
+        let timings = time::<day25::Day25>(">>\n..", 2).unwrap();
+        assert!(timings.part_two.is_none());
+        assert!(time::<day06::Day06>("3,x", 1).is_err());
+    }
+
+    #[test]
+    fn regression_needs_threshold_and_noise_floor() {
+        assert_eq!(regression(100_000, 150_000, 10.0), Some(50.0));
+        assert_eq!(regression(100_000, 105_000, 10.0), None);
+        assert_eq!(regression(100, 5_000, 10.0), None);
+        assert_eq!(regression(200_000, 100_000, 10.0), None);
+    }
+
+    #[test]
+    fn table_flags_regressions() {
+        let baseline = Baseline {
+            records: vec![record(1, 100_000, Some(100_000))],
+        };
+        let current = Baseline {
+            records: vec![record(1, 300_000, Some(100_000)), record(25, 1_000, None)],
+        };
+        let (rendered, regressions) = table(&current, Some(&baseline), 10.0);
+        assert_eq!(regressions, 1);
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("Day  Input"));
+        assert!(lines[1].contains("300.0µs (+200%)"));
+        assert!(lines[2].ends_with('-'));
+    }
+
+    #[test]
+    fn baseline_round_trips_through_json() {
+        let baseline = Baseline {
+            records: vec![record(1, 100, Some(200)), record(25, 300, None)],
+        };
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert_eq!(serde_json::from_str::<Baseline>(&json).unwrap(), baseline);
+    }
+}
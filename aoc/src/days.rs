@@ -0,0 +1,55 @@
+use aoc_common::{Part, Result, Solution};
+
+use crate::bench::{self, Timings};
+
+pub struct Day {
+    pub number: u8,
+    /// Parses the raw puzzle input and solves one part, see [`aoc_common::solve`].
+    pub solve: fn(&str, Part) -> Option<Result<String>>,
+    /// Times parsing and both parts, see [`bench::time`].
+    pub bench: fn(&str, u32) -> Result<Timings>,
+}
+
+const fn day<S: Solution>() -> Day {
+    Day {
+        number: S::DAY,
+        solve: aoc_common::solve::<S>,
+        bench: bench::time::<S>,
+    }
+}
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+pub static DAYS: &[Day] = &[
+    day::<day01::Day01>(),
+    day::<day02::Day02>(),
+    day::<day03::Day03>(),
+    day::<day04::Day04>(),
+    day::<day05::Day05>(),
+    day::<day06::Day06>(),
+    day::<day07::Day07>(),
+    day::<day08::Day08>(),
+    day::<day09::Day09>(),
+    day::<day10::Day10>(),
+    day::<day11::Day11>(),
+    day::<day14::Day14>(),
+    day::<day25::Day25>(),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{find, DAYS};
+
+    #[test]
+    fn days_are_sorted_and_unique() {
+        assert!(DAYS.windows(2).all(|w| w[0].number < w[1].number));
+    }
+
+    #[test]
+    fn find_day() {
+        assert_eq!(find(5).map(|day| day.number), Some(5));
+        assert!(find(12).is_none());
+    }
+}
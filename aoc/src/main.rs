@@ -0,0 +1,225 @@
+use std::{path::PathBuf, process::ExitCode, time::Instant};
+
+use aoc_common::{
+    input::{normalize, workspace_file, Source},
+    output::{self, Format},
+    Part,
+};
+use bench::{Baseline, Record};
+use clap::{Parser, Subcommand};
+
+mod bench;
+mod days;
+mod verify;
+
+#[derive(Parser)]
+#[command(about = "Run Advent of Code 2021 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every implemented day if no day is given.
+    Run {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input to use instead of the day's default, `-` for stdin.
+        #[arg(short, long, requires = "day")]
+        input: Option<PathBuf>,
+        /// How to print the answers.
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+    },
+    /// Time parsing and both parts of every day on its small and full input.
+    Bench {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// How often each stage runs, the fastest run counts.
+        #[arg(short, long, default_value_t = 10)]
+        runs: u32,
+        /// Write the results to this JSON file.
+        #[arg(short, long)]
+        save: Option<PathBuf>,
+        /// Compare against the results of a previous `--save`.
+        #[arg(short, long)]
+        baseline: Option<PathBuf>,
+        /// Slowdown in percent that counts as a regression.
+        #[arg(short, long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Check every day against the known answers in its `answers.toml`.
+    Verify {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+    },
+}
+
+fn main() -> ExitCode {
+    let Cli { command } = Cli::parse();
+    match command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+        } => run(day, part, input, format),
+        Command::Bench {
+            day,
+            runs,
+            save,
+            baseline,
+            threshold,
+        } => bench(day, runs, save, baseline, threshold),
+        Command::Verify { day } => verify(day),
+    }
+}
+
+/// The requested day, or every implemented day if none is given.
+fn select(day: Option<u8>) -> Option<Vec<&'static days::Day>> {
+    match day {
+        Some(number) => match days::find(number) {
+            Some(day) => Some(vec![day]),
+            None => {
+                eprintln!("Day {number} is not implemented.");
+                None
+            }
+        },
+        None => Some(days::DAYS.iter().collect()),
+    }
+}
+
+fn run(day: Option<u8>, part: Option<u8>, input: Option<PathBuf>, format: Format) -> ExitCode {
+    let Some(selected) = select(day) else {
+        return ExitCode::FAILURE;
+    };
+    let parts = match part.and_then(Part::from_number) {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let mut failed = false;
+    for day in selected {
+        let source = match &input {
+            Some(path) => Source::from(path.as_os_str()),
+            None => Source::default_for(day.number),
+        };
+        let raw = match source.read() {
+            Ok(raw) => raw,
+            Err(e) => {
+                eprintln!("Day {:02}: {e}", day.number);
+                failed = true;
+                continue;
+            }
+        };
+        let content = normalize(&raw);
+        let hash = match format {
+            Format::Text => String::new(),
+            Format::Json => output::sha256_hex(raw.as_bytes()),
+        };
+
+        for &part in parts.iter() {
+            let start = Instant::now();
+            let Some(answer) = (day.solve)(&content, part) else {
+                continue;
+            };
+            let elapsed = start.elapsed();
+            let p = part.number();
+            match answer {
+                Ok(answer) if format == Format::Json => {
+                    let record = output::Record::new(day.number, part, answer, &hash, elapsed);
+                    println!("{}", record.to_json());
+                }
+                Ok(answer) => println!("Day {:02} Part {p}: {answer}", day.number),
+                Err(e) => {
+                    eprintln!("Day {:02} Part {p}: {}", day.number, source.annotate(e));
+                    failed = true;
+                }
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn bench(
+    day: Option<u8>,
+    runs: u32,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+) -> ExitCode {
+    let Some(selected) = select(day) else {
+        return ExitCode::FAILURE;
+    };
+    let baseline = match baseline.as_deref().map(Baseline::load).transpose() {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut failed = false;
+    let mut current = Baseline::default();
+    for day in selected {
+        for name in bench::INPUTS {
+            let source = Source::Path(workspace_file(day.number, name));
+            let timings = source
+                .load()
+                .and_then(|content| (day.bench)(&content, runs).map_err(|e| source.annotate(e)));
+            match timings {
+                Ok(timings) => current.records.push(Record::new(day.number, name, timings)),
+                Err(e) => {
+                    eprintln!("Day {:02}: {e}", day.number);
+                    failed = true;
+                }
+            }
+        }
+    }
+
+    let (table, regressions) = bench::table(&current, baseline.as_ref(), threshold);
+    println!("{table}");
+    if regressions > 0 {
+        eprintln!("{regressions} stage(s) regressed by more than {threshold}%.");
+        failed = true;
+    }
+    if let Some(path) = save {
+        if let Err(e) = current.save(&path) {
+            eprintln!("{e}");
+            failed = true;
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn verify(day: Option<u8>) -> ExitCode {
+    let Some(selected) = select(day) else {
+        return ExitCode::FAILURE;
+    };
+
+    let checks: Vec<_> = selected.into_iter().flat_map(verify::verify).collect();
+    for check in &checks {
+        println!("{check}");
+    }
+    let (passed, failed, missing) = verify::summarize(&checks);
+    println!("{passed} passed, {failed} failed, {missing} missing");
+
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,247 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs, io,
+};
+
+use aoc_common::{
+    input::{workspace_file, Source},
+    Error, Part, Result,
+};
+use serde::Deserialize;
+
+use crate::{bench::INPUTS, days::Day};
+
+/// Name of the manifest in every day's directory.
+pub const MANIFEST: &str = "answers.toml";
+
+/// A known answer, written as a number or a string in the manifest.
+#[derive(Debug, PartialEq, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// The known answers for one input file.
+#[derive(Debug, PartialEq, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Answers {
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+}
+
+impl Answers {
+    fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part_one.as_ref(),
+            Part::Two => self.part_two.as_ref(),
+        }
+    }
+}
+
+/// Known answers of a day, keyed by input file name.
+pub type Manifest = BTreeMap<String, Answers>;
+
+/// Loads the manifest of `day`, `None` if it has none.
+pub fn load(day: u8) -> Result<Option<Manifest>> {
+    let path = workspace_file(day, MANIFEST);
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(Error::from(e).in_file(path)),
+    };
+
+    toml::from_str(&content)
+        .map(Some)
+        .map_err(|e| Error::from(io::Error::new(io::ErrorKind::InvalidData, e)).in_file(path))
+}
+
+/// Result of checking one part against its known answer.
+#[derive(Debug)]
+pub enum Outcome {
+    Pass,
+    Fail {
+        expected: Answer,
+        actual: String,
+    },
+    /// The manifest has no answer, `actual` is what the solution computed.
+    Missing {
+        actual: Option<String>,
+    },
+    Error(Error),
+}
+
+impl Outcome {
+    fn of(expected: Option<&Answer>, actual: Result<String>) -> Self {
+        match (expected, actual) {
+            (_, Err(e)) => Outcome::Error(e),
+            (None, Ok(actual)) => Outcome::Missing {
+                actual: Some(actual),
+            },
+            (Some(expected), Ok(actual)) if expected.to_string() == actual => Outcome::Pass,
+            (Some(expected), Ok(actual)) => Outcome::Fail {
+                expected: expected.clone(),
+                actual,
+            },
+        }
+    }
+}
+
+/// One line of the `verify` report.
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub input: String,
+    pub part: Option<Part>,
+    pub outcome: Outcome,
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {:02} {}", self.day, self.input)?;
+        if let Some(part) = self.part {
+            write!(f, " Part {}", part.number())?;
+        }
+        match &self.outcome {
+            Outcome::Pass => write!(f, ": pass"),
+            Outcome::Fail { expected, actual } => {
+                write!(f, ": FAIL, expected {expected}, got {actual}")
+            }
+            Outcome::Missing {
+                actual: Some(actual),
+            } => {
+                write!(f, ": missing answer, got {actual}")
+            }
+            Outcome::Missing { actual: None } => write!(f, ": missing answers"),
+            Outcome::Error(e) => write!(f, ": ERROR, {e}"),
+        }
+    }
+}
+
+/// Checks both parts of `day` on `content` against `answers`.
+pub fn check(day: &Day, input: &str, content: &str, answers: &Answers) -> Vec<Check> {
+    [Part::One, Part::Two]
+        .into_iter()
+        .filter_map(|part| {
+            let actual = (day.solve)(content, part)?;
+            Some(Check {
+                day: day.number,
+                input: input.to_string(),
+                part: Some(part),
+                outcome: Outcome::of(answers.get(part), actual),
+            })
+        })
+        .collect()
+}
+
+/// Checks `day` on every input of its manifest and on the standard inputs.
+pub fn verify(day: &Day) -> Vec<Check> {
+    let failure = |input: &str, outcome| {
+        vec![Check {
+            day: day.number,
+            input: input.to_string(),
+            part: None,
+            outcome,
+        }]
+    };
+    let manifest = match load(day.number) {
+        Ok(Some(manifest)) => manifest,
+        Ok(None) => return failure(MANIFEST, Outcome::Missing { actual: None }),
+        Err(e) => return failure(MANIFEST, Outcome::Error(e)),
+    };
+
+    let mut inputs: Vec<&str> = manifest.keys().map(String::as_str).collect();
+    for input in INPUTS {
+        let exists = workspace_file(day.number, input).exists();
+        if exists && !inputs.contains(&input) {
+            inputs.push(input);
+        }
+    }
+
+    inputs
+        .into_iter()
+        .flat_map(|input| {
+            let source = Source::Path(workspace_file(day.number, input));
+            match source.load() {
+                Ok(content) => {
+                    let answers = manifest.get(input).cloned().unwrap_or_default();
+                    check(day, input, &content, &answers)
+                        .into_iter()
+                        .map(|mut c| {
+                            if let Outcome::Error(e) = c.outcome {
+                                c.outcome = Outcome::Error(source.annotate(e));
+                            }
+                            c
+                        })
+                        .collect()
+                }
+                Err(e) => failure(input, Outcome::Error(e)),
+            }
+        })
+        .collect()
+}
+
+/// Counts of passed, failed and missing checks, errors count as failures.
+pub fn summarize(checks: &[Check]) -> (usize, usize, usize) {
+    checks
+        .iter()
+        .fold((0, 0, 0), |(pass, fail, missing), check| {
+            match check.outcome {
+                Outcome::Pass => (pass + 1, fail, missing),
+                Outcome::Fail { .. } | Outcome::Error(_) => (pass, fail + 1, missing),
+                Outcome::Missing { .. } => (pass, fail, missing + 1),
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, load, summarize, verify, Answer, Answers, Outcome};
+    use crate::days::find;
+
+    #[test]
+    fn manifests_parse() {
+        let manifest = load(25).unwrap().unwrap();
+        let answers = &manifest["small_input.txt"];
+        assert_eq!(answers.part_one, Some(Answer::Number(58)));
+        assert_eq!(answers.part_two, None);
+        assert!(load(12).unwrap().is_none());
+    }
+
+    #[test]
+    fn check_reports_every_outcome() {
+        let day = find(6).unwrap();
+        let answers = Answers {
+            part_one: Some(Answer::Number(5934)),
+            part_two: Some(Answer::Text("1".to_string())),
+        };
+        let checks = check(day, "small_input.txt", "3,4,3,1,2", &answers);
+        assert!(matches!(checks[0].outcome, Outcome::Pass));
+        assert!(matches!(checks[1].outcome, Outcome::Fail { .. }));
+        assert_eq!(
+            checks[1].to_string(),
+            "Day 06 small_input.txt Part 2: FAIL, expected 1, got 26984457539"
+        );
+
+        let checks = check(day, "other.txt", "3,4,3,1,2", &Answers::default());
+        assert_eq!(summarize(&checks), (0, 0, 2));
+        let checks = check(day, "other.txt", "3,x", &Answers::default());
+        assert_eq!(summarize(&checks), (0, 2, 0));
+    }
+
+    #[test]
+    fn verify_small_day() {
+        let checks = verify(find(7).unwrap());
+        assert_eq!(summarize(&checks), (4, 0, 0));
+    }
+}
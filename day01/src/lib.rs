@@ -0,0 +1,429 @@
+//! Day 1: Sonar Sweep.
+//!
+//! Counts how often a series of depth measurements increases.
+
+use aoc_common::{
+    parse::{number, parse_lines},
+    Error, ParseError, Solution,
+};
+use std::collections::VecDeque;
+use std::fs;
+use std::io::BufRead;
+
+pub mod smooth;
+pub mod stats;
+pub mod table;
+
+/// [`Solution`] for day 1.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<i32>;
+    type PartOne = i32;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> aoc_common::Result<Self::PartOne> {
+        Ok(solve_part_1(input))
+    }
+
+    fn part_two(input: &Self::Input) -> aoc_common::Result<Self::PartTwo> {
+        Ok(solve_part_2(input))
+    }
+}
+
+/// Counts the measurements that are larger than the previous one.
+pub fn solve_part_1(depths: &[i32]) -> i32 {
+    depths
+        .iter()
+        .fold((None, 0), |acc, depth| {
+            let (previous, count) = acc;
+            if let Some(p) = previous {
+                (Some(depth), count + (if p < depth { 1 } else { 0 }))
+            } else {
+                (Some(depth), count)
+            }
+        })
+        .1
+}
+
+/// Counts increases of the sums over a sliding window of three measurements.
+pub fn solve_part_2(depths: &[i32]) -> usize {
+    count_window_increases(depths.iter().copied(), 3)
+}
+
+/// Counts increases of the sums over a sliding window of `size` measurements.
+///
+/// # Panics
+///
+/// If `size` is 0.
+pub fn count_window_increases(depths: impl IntoIterator<Item = i32>, size: usize) -> usize {
+    let mut increases = WindowIncreases::new(size);
+    depths.into_iter().for_each(|depth| increases.push(depth));
+    increases.count()
+}
+
+/// The last `size` measurements, the building block of every sliding window
+/// in this crate.
+#[derive(Debug, Clone)]
+pub struct Window {
+    depths: VecDeque<i32>,
+    size: usize,
+}
+
+impl Window {
+    /// # Panics
+    ///
+    /// If `size` is 0.
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0, "windows must hold at least one measurement");
+        Self {
+            // Grows with the measurements, so a huge window over a short
+            // input costs no more than the input.
+            depths: VecDeque::new(),
+            size,
+        }
+    }
+
+    /// Adds `depth`, returning the measurement it pushed out of the window.
+    pub fn push(&mut self, depth: i32) -> Option<i32> {
+        self.depths.push_back(depth);
+        if self.depths.len() > self.size {
+            self.depths.pop_front()
+        } else {
+            None
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.depths.len() == self.size
+    }
+
+    /// The measurements in the window, oldest first.
+    pub fn depths(&self) -> impl Iterator<Item = i32> + '_ {
+        self.depths.iter().copied()
+    }
+
+    /// Empties the window.
+    pub fn clear(&mut self) {
+        self.depths.clear();
+    }
+}
+
+/// Counts increasing window sums one measurement at a time.
+///
+/// Consecutive windows share all but one measurement, so a window sum grows
+/// exactly when the measurement entering the window is larger than the one
+/// leaving it. Only the last `size` measurements are kept, and no sum is ever
+/// computed.
+#[derive(Debug, Clone)]
+pub struct WindowIncreases {
+    window: Window,
+    count: usize,
+}
+
+impl WindowIncreases {
+    /// # Panics
+    ///
+    /// If `size` is 0.
+    pub fn new(size: usize) -> Self {
+        Self {
+            window: Window::new(size),
+            count: 0,
+        }
+    }
+
+    pub fn push(&mut self, depth: i32) {
+        if self
+            .window
+            .push(depth)
+            .is_some_and(|leaving| depth > leaving)
+        {
+            self.count += 1;
+        }
+    }
+
+    /// Marks a missing reading, no window spans it.
+    pub fn gap(&mut self) {
+        self.window.clear();
+    }
+
+    /// Number of increases among the windows seen so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+/// Counts increases of the sums over a sliding window of `size` readings.
+///
+/// Gaps end the current window, so no window spans a missing reading and
+/// windows on either side of a gap are never compared.
+///
+/// # Panics
+///
+/// If `size` is 0.
+pub fn count_reading_increases(readings: impl IntoIterator<Item = Reading>, size: usize) -> usize {
+    let mut increases = WindowIncreases::new(size);
+    for reading in readings {
+        match reading {
+            Some(depth) => increases.push(depth),
+            None => increases.gap(),
+        }
+    }
+    increases.count()
+}
+
+/// Counts the increases of single readings and of windows of `size` readings
+/// in one pass over `reader`, see [`read_depths`].
+pub fn stream_increases(
+    reader: impl BufRead,
+    size: usize,
+    mode: Mode,
+) -> aoc_common::Result<(usize, usize)> {
+    let mut single = WindowIncreases::new(1);
+    let mut windows = WindowIncreases::new(size);
+    for reading in read_depths(reader, mode) {
+        match reading? {
+            Some(depth) => {
+                single.push(depth);
+                windows.push(depth);
+            }
+            None => {
+                single.gap();
+                windows.gap();
+            }
+        }
+    }
+
+    Ok((single.count(), windows.count()))
+}
+
+/// Sums of every window of three consecutive measurements.
+pub fn trigram_sum(data: &[i32]) -> Vec<i32> {
+    data.windows(3).map(|window| window.iter().sum()).collect()
+}
+
+/// How to treat lines that are not depth measurements.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Mode {
+    /// Reject the input at the first malformed line.
+    #[default]
+    Strict,
+    /// Keep a gap in place of every malformed line.
+    Lenient,
+}
+
+/// A depth measurement, or `None` for a gap where a malformed line was.
+pub type Reading = Option<i32>;
+
+/// Reads the depth measurements from the file at `path`.
+pub fn parse(path: &str) -> aoc_common::Result<Vec<i32>> {
+    Ok(parse_input(&fs::read_to_string(path)?)?)
+}
+
+/// Reads one reading per line from `reader`, see [`parse_readings`].
+///
+/// Lines are read one at a time, so the input never has to fit into memory.
+/// Like [`aoc_common::input::normalize`], trailing whitespace and blank lines
+/// at the end of the input are ignored.
+pub fn read_depths<R: BufRead>(reader: R, mode: Mode) -> Depths<R> {
+    Depths {
+        reader,
+        mode,
+        line: String::new(),
+        line_number: 0,
+        blanks: 0,
+        held: false,
+    }
+}
+
+/// Iterator returned by [`read_depths`].
+#[derive(Debug)]
+pub struct Depths<R> {
+    reader: R,
+    mode: Mode,
+    line: String,
+    line_number: usize,
+    /// Blank lines read but not yet returned, they only count as readings
+    /// once a line with content follows.
+    blanks: usize,
+    /// Whether `line` is such a line, read but not yet returned.
+    held: bool,
+}
+
+impl<R> Depths<R> {
+    fn reading(&self, line: &str, line_number: usize) -> aoc_common::Result<Reading> {
+        parse_reading(line, self.mode).map_err(|e| Error::from(e.offset(line_number - 1, 0)))
+    }
+}
+
+impl<R: BufRead> Iterator for Depths<R> {
+    type Item = aoc_common::Result<Reading>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.held && self.blanks > 0 {
+                let line_number = self.line_number - self.blanks;
+                self.blanks -= 1;
+                return Some(self.reading("", line_number));
+            }
+            if self.held {
+                self.held = false;
+                return Some(self.reading(self.line.trim_end(), self.line_number));
+            }
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => {
+                    self.line_number += 1;
+                    if self.line.trim_end().is_empty() {
+                        self.blanks += 1;
+                    } else {
+                        self.held = true;
+                    }
+                }
+                Err(e) => return Some(Err(e.into())),
+            }
+        }
+    }
+}
+
+/// Parses one depth measurement per line.
+pub fn parse_input(s: &str) -> Result<Vec<i32>, ParseError> {
+    parse_lines(s, |line| number(line, 1))
+}
+
+/// Parses one reading per line, treating malformed lines according to `mode`.
+pub fn parse_readings(s: &str, mode: Mode) -> Result<Vec<Reading>, ParseError> {
+    parse_lines(s, |line| parse_reading(line, mode))
+}
+
+fn parse_reading(line: &str, mode: Mode) -> Result<Reading, ParseError> {
+    match (number(line, 1), mode) {
+        (Ok(depth), _) => Ok(Some(depth)),
+        (Err(_), Mode::Lenient) => Ok(None),
+        (Err(e), Mode::Strict) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use aoc_common::{input::normalize, ParseError, Solution};
+
+    use super::{
+        count_reading_increases, count_window_increases, parse, parse_input, parse_readings,
+        read_depths, solve_part_1, solve_part_2, stream_increases, trigram_sum, Day01, Mode,
+    };
+    #[test]
+    fn parse_test() {
+        let expected = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        let actual = parse("small_input.txt").unwrap();
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn small_input_part_1() {
+        let expected = 7;
+        let actual = solve_part_1(&[199, 200, 208, 210, 200, 207, 240, 269, 260, 263]);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn small_input_part_2() {
+        let expected = 5;
+        let actual = solve_part_2(&[199, 200, 208, 210, 200, 207, 240, 269, 260, 263]);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn small_input_trigramsum() {
+        let expected = vec![607, 618, 618, 617, 647, 716, 769, 792];
+
+        let v: Vec<i32> = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+        let actual = trigram_sum(&v);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn window_increases_match_window_sums() {
+        let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        for size in 1..=depths.len() + 1 {
+            let sums: Vec<i32> = depths.windows(size).map(|w| w.iter().sum()).collect();
+            let expected = solve_part_1(&sums) as usize;
+            assert_eq!(
+                count_window_increases(depths, size),
+                expected,
+                "size {size}"
+            );
+        }
+    }
+
+    #[test]
+    fn huge_windows_only_hold_what_was_read() {
+        assert_eq!(count_window_increases([1, 2, 3], usize::MAX), 0);
+    }
+
+    #[test]
+    fn stream_increases_reads_lines() {
+        let input = "199\r\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+        assert_eq!(
+            stream_increases(input.as_bytes(), 3, Mode::Strict).unwrap(),
+            (7, 5)
+        );
+    }
+
+    #[test]
+    fn streaming_and_parsing_agree_on_blank_lines() {
+        let input = "1\n2 \r\n3\n\n \n";
+        let depths = Day01::parse(&normalize(input)).unwrap();
+        let streamed = stream_increases(input.as_bytes(), 1, Mode::Strict).unwrap();
+        assert_eq!(streamed.0, solve_part_1(&depths) as usize);
+
+        let input = "1\n\n2\n";
+        assert!(Day01::parse(&normalize(input)).is_err());
+        assert!(stream_increases(input.as_bytes(), 1, Mode::Strict).is_err());
+    }
+
+    #[test]
+    fn strict_parse_reports_line_and_content() {
+        let expected = ParseError::UnexpectedToken {
+            token: "20x".to_string(),
+            expected: "a number",
+            line: 3,
+            column: 1,
+        };
+        assert_eq!(parse_input("199\n200\n20x\n210"), Err(expected));
+
+        let error = read_depths("199\n\n208".as_bytes(), Mode::Strict)
+            .find_map(Result::err)
+            .unwrap();
+        assert_eq!((error.line(), error.column()), (Some(2), Some(1)));
+    }
+
+    #[test]
+    fn lenient_gaps_split_windows() {
+        let readings = parse_readings("1\n2\n3\nx\n4\n5\n6\n7", Mode::Lenient).unwrap();
+        assert_eq!(readings[3], None);
+        // Windows of two: 1+2 < 2+3, then 4+5 < 5+6 < 6+7, but never 2+3 < 4+5.
+        assert_eq!(count_reading_increases(readings.iter().copied(), 2), 3);
+        assert_eq!(count_reading_increases(readings, 1), 5);
+
+        let input = "1\n2\nx\n3\n";
+        assert_eq!(
+            stream_increases(input.as_bytes(), 1, Mode::Lenient).unwrap(),
+            (1, 1)
+        );
+        assert!(stream_increases(input.as_bytes(), 1, Mode::Strict).is_err());
+    }
+}
@@ -1,78 +1,102 @@
-use std::collections::VecDeque;
-use std::fs;
-use std::io;
+use std::process::ExitCode;
 
-fn main() -> io::Result<()> {
-    let input = parse("input.txt")?;
-    println!("Answer Part 1: {}", solve_part_1(&input));
-    println!("Answer Part 2: {}", solve_part_2(&input));
+use aoc_common::{cli, Solution};
+use clap::Parser;
+use day01::{
+    smooth::{stream_smoothed_increases, Smoothing},
+    stats::stream_profile,
+    stream_increases,
+    table::{self, Column, Header},
+    Day01, Mode,
+};
 
-    Ok(())
+#[derive(Debug, Parser)]
+#[command(about = "Solve both parts of Advent of Code 2021 day 1")]
+struct Cli {
+    #[command(flatten)]
+    common: cli::Args,
+    /// Stream the input and compare windows of this many measurements in part
+    /// two, holding only one window in memory.
+    #[arg(short, long, conflicts_with = "format", value_parser = clap::value_parser!(u64).range(1..))]
+    window: Option<u64>,
+    /// Stream the input like `--window 3`, but keep a gap in place of every
+    /// malformed line instead of rejecting it. No window spans a gap.
+    #[arg(short, long, conflicts_with = "format")]
+    lenient: bool,
+    /// Print statistics of the depths and of their window sums instead of the
+    /// answers.
+    #[arg(long, conflicts_with = "format")]
+    stats: bool,
+    /// Count the increases after smoothing the depths with `sum:N`, `mean:N`,
+    /// `ema:ALPHA` or `median:N` instead of the answers. Can be repeated to
+    /// compare filters.
+    #[arg(long, value_name = "FILTER", conflicts_with_all = ["format", "stats"])]
+    smooth: Vec<Smoothing>,
+    /// Read a CSV or whitespace separated table and count the increases of
+    /// these columns side by side, picked by header name or 1-based position.
+    #[arg(short, long, value_name = "COLUMN", value_delimiter = ',', conflicts_with_all = ["format", "stats", "smooth"])]
+    columns: Vec<Column>,
+    /// Treat the first line of the table as a header, even if the picked
+    /// columns hold numbers there.
+    #[arg(long, requires = "columns", conflicts_with = "no_header")]
+    header: bool,
+    /// Treat the first line of the table as a row, even if it does not look
+    /// like one.
+    #[arg(long, requires = "columns")]
+    no_header: bool,
 }
 
-fn solve_part_1(depths: &Vec<i32>) -> i32 {
-    depths
-        .into_iter()
-        .fold((None, 0), |acc, depth| {
-            let (previous, count) = acc;
-            if let Some(p) = previous {
-                (Some(depth), count + (if p < depth { 1 } else { 0 }))
-            } else {
-                (Some(depth), count)
-            }
-        })
-        .1
-}
-
-fn solve_part_2(depths: &Vec<i32>) -> i32 {
-    solve_part_1(&trigram_sum(depths))
-}
-
-fn trigram_sum(data: &Vec<i32>) -> Vec<i32> {
-    data.windows(3)
-        .map(|window| window.into_iter().sum())
-        .collect()
-}
-
-fn parse(path: &str) -> io::Result<Vec<i32>> {
-    Ok(fs::read_to_string(path)?
-        .lines()
-        .filter_map(|line| line.parse::<i32>().ok())
-        .collect::<Vec<i32>>())
-}
-
-#[cfg(test)]
-mod tests {
-
-    use super::{parse, solve_part_1, solve_part_2, trigram_sum};
-    #[test]
-    fn parse_test() {
-        let expected = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
-        let actual = parse("small_input.txt").unwrap();
-        assert_eq!(expected, actual)
-    }
-
-    #[test]
-    fn small_input_part_1() {
-        let expected = 7;
-        let actual = solve_part_1(&vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263]);
-        assert_eq!(expected, actual);
+fn main() -> ExitCode {
+    let args = Cli::parse();
+    let source = args.common.source(Day01::DAY);
+    let streaming = args.window.is_some()
+        || args.lenient
+        || args.stats
+        || !args.smooth.is_empty()
+        || !args.columns.is_empty();
+    if !streaming {
+        return match aoc_common::run::<Day01>(&source, args.common.format()) {
+            Ok(_) => ExitCode::SUCCESS,
+            Err(e) => aoc_common::report(e),
+        };
     }
 
-    #[test]
-    fn small_input_part_2() {
-        let expected = 5;
-        let actual = solve_part_2(&vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263]);
-        assert_eq!(expected, actual);
-    }
-
-    #[test]
-    fn small_input_trigramsum() {
-        let expected = vec![607, 618, 618, 617, 647, 716, 769, 792];
-
-        let v: Vec<i32> = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
-
-        let actual = trigram_sum(&v);
-        assert_eq!(expected, actual);
+    let size = args.window.unwrap_or(3) as usize;
+    let mode = if args.lenient {
+        Mode::Lenient
+    } else {
+        Mode::Strict
+    };
+    let output = source.open().and_then(|reader| {
+        let output = if !args.columns.is_empty() {
+            let header = match (args.header, args.no_header) {
+                (true, _) => Header::Present,
+                (_, true) => Header::Absent,
+                _ => Header::Detect,
+            };
+            table::analyse(reader, &args.columns, header, size, mode)
+                .map(|results| table::render(&results, size) + "\n")
+        } else if args.stats {
+            stream_profile(reader, size, mode).map(|report| report.to_string())
+        } else if !args.smooth.is_empty() {
+            stream_smoothed_increases(reader, &args.smooth, mode).map(|counts| {
+                args.smooth
+                    .iter()
+                    .zip(counts)
+                    .map(|(smoothing, count)| format!("{smoothing}: {count}\n"))
+                    .collect()
+            })
+        } else {
+            stream_increases(reader, size, mode)
+                .map(|(part_one, part_two)| format!("Part One: {part_one}\nPart Two: {part_two}\n"))
+        };
+        output.map_err(|e| source.annotate(e))
+    });
+    match output {
+        Ok(output) => {
+            print!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => aoc_common::report(e),
     }
 }
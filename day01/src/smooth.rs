@@ -0,0 +1,348 @@
+//! Smoothing filters to run over the depths before counting increases.
+
+use std::{
+    fmt::{self, Display},
+    io::BufRead,
+    str::FromStr,
+};
+
+use aoc_common::{
+    parse::{exact_fields, number},
+    ParseError,
+};
+
+use crate::{read_depths, stats::WindowSums, Mode, Reading, Window};
+
+/// Turns depths into a smoothed series, one depth at a time.
+pub trait Filter {
+    /// Adds `depth`, returning the next smoothed value once there is one.
+    fn push(&mut self, depth: i32) -> Option<f64>;
+
+    /// Forgets everything seen so far, so no value spans a missing reading.
+    fn reset(&mut self);
+}
+
+/// Sum over a sliding window, the series part two counts increases of.
+#[derive(Debug, Clone)]
+pub struct MovingSum(WindowSums);
+
+impl MovingSum {
+    /// # Panics
+    ///
+    /// If `width` is 0.
+    pub fn new(width: usize) -> Self {
+        Self(WindowSums::new(width))
+    }
+
+    pub fn width(&self) -> usize {
+        self.0.size()
+    }
+}
+
+impl Filter for MovingSum {
+    fn push(&mut self, depth: i32) -> Option<f64> {
+        self.0.push(depth).map(|sum| sum as f64)
+    }
+
+    fn reset(&mut self) {
+        self.0.gap();
+    }
+}
+
+/// Mean over a sliding window.
+#[derive(Debug, Clone)]
+pub struct MovingAverage(MovingSum);
+
+impl MovingAverage {
+    /// # Panics
+    ///
+    /// If `width` is 0.
+    pub fn new(width: usize) -> Self {
+        Self(MovingSum::new(width))
+    }
+}
+
+impl Filter for MovingAverage {
+    fn push(&mut self, depth: i32) -> Option<f64> {
+        let width = self.0.width() as f64;
+        self.0.push(depth).map(|sum| sum / width)
+    }
+
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+/// Exponential moving average, weighing every new depth with `alpha`.
+#[derive(Debug, Clone)]
+pub struct Exponential {
+    alpha: f64,
+    average: Option<f64>,
+}
+
+impl Exponential {
+    /// # Panics
+    ///
+    /// If `alpha` is not in `(0, 1]`.
+    pub fn new(alpha: f64) -> Self {
+        assert!(alpha > 0.0 && alpha <= 1.0, "alpha must be in (0, 1]");
+        Self {
+            alpha,
+            average: None,
+        }
+    }
+}
+
+impl Filter for Exponential {
+    fn push(&mut self, depth: i32) -> Option<f64> {
+        let depth = f64::from(depth);
+        let average = match self.average {
+            Some(average) => self.alpha * depth + (1.0 - self.alpha) * average,
+            None => depth,
+        };
+        self.average = Some(average);
+        Some(average)
+    }
+
+    fn reset(&mut self) {
+        self.average = None;
+    }
+}
+
+/// Median over a sliding window, the mean of the middle two for even widths.
+#[derive(Debug, Clone)]
+pub struct Median {
+    window: Window,
+    sorted: Vec<i32>,
+}
+
+impl Median {
+    /// # Panics
+    ///
+    /// If `width` is 0.
+    pub fn new(width: usize) -> Self {
+        Self {
+            window: Window::new(width),
+            sorted: Vec::new(),
+        }
+    }
+}
+
+impl Filter for Median {
+    fn push(&mut self, depth: i32) -> Option<f64> {
+        self.window.push(depth);
+        if !self.window.is_full() {
+            return None;
+        }
+        self.sorted.clear();
+        self.sorted.extend(self.window.depths());
+        self.sorted.sort_unstable();
+        let middle = self.sorted.len() / 2;
+        if self.sorted.len() % 2 == 1 {
+            Some(f64::from(self.sorted[middle]))
+        } else {
+            Some((f64::from(self.sorted[middle - 1]) + f64::from(self.sorted[middle])) / 2.0)
+        }
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+/// One of the built-in filters, written `sum:3`, `mean:3`, `ema:0.5` or
+/// `median:3`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Smoothing {
+    Sum(usize),
+    Average(usize),
+    Exponential(f64),
+    Median(usize),
+}
+
+impl Smoothing {
+    pub fn filter(&self) -> Box<dyn Filter> {
+        match *self {
+            Smoothing::Sum(width) => Box::new(MovingSum::new(width)),
+            Smoothing::Average(width) => Box::new(MovingAverage::new(width)),
+            Smoothing::Exponential(alpha) => Box::new(Exponential::new(alpha)),
+            Smoothing::Median(width) => Box::new(Median::new(width)),
+        }
+    }
+}
+
+impl FromStr for Smoothing {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [(_, name), (column, parameter)] = exact_fields(s, ":")?;
+        let width = || match number(parameter, column)? {
+            0 => Err(ParseError::unexpected_token(
+                parameter,
+                "a width of at least 1",
+                column,
+            )),
+            width => Ok(width),
+        };
+        match name {
+            "sum" => Ok(Smoothing::Sum(width()?)),
+            "mean" => Ok(Smoothing::Average(width()?)),
+            "median" => Ok(Smoothing::Median(width()?)),
+            "ema" => match parameter.parse() {
+                Ok(alpha) if alpha > 0.0 && alpha <= 1.0 => Ok(Smoothing::Exponential(alpha)),
+                _ => Err(ParseError::unexpected_token(
+                    parameter,
+                    "a factor in (0, 1]",
+                    column,
+                )),
+            },
+            _ => Err(ParseError::unexpected_token(
+                name,
+                "one of `sum`, `mean`, `ema` or `median`",
+                1,
+            )),
+        }
+    }
+}
+
+impl Display for Smoothing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Smoothing::Sum(width) => write!(f, "sum:{width}"),
+            Smoothing::Average(width) => write!(f, "mean:{width}"),
+            Smoothing::Exponential(alpha) => write!(f, "ema:{alpha}"),
+            Smoothing::Median(width) => write!(f, "median:{width}"),
+        }
+    }
+}
+
+/// Counts the increases of a smoothed series, one reading at a time.
+pub struct SmoothedIncreases {
+    filter: Box<dyn Filter>,
+    previous: Option<f64>,
+    count: usize,
+}
+
+impl SmoothedIncreases {
+    pub fn new(filter: Box<dyn Filter>) -> Self {
+        Self {
+            filter,
+            previous: None,
+            count: 0,
+        }
+    }
+
+    pub fn push(&mut self, reading: Reading) {
+        let Some(depth) = reading else {
+            self.filter.reset();
+            self.previous = None;
+            return;
+        };
+        if let Some(value) = self.filter.push(depth) {
+            if self.previous.is_some_and(|previous| value > previous) {
+                self.count += 1;
+            }
+            self.previous = Some(value);
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+/// Counts the increases of `readings` after running them through `filter`.
+pub fn count_smoothed_increases(
+    readings: impl IntoIterator<Item = Reading>,
+    filter: Box<dyn Filter>,
+) -> usize {
+    let mut increases = SmoothedIncreases::new(filter);
+    readings
+        .into_iter()
+        .for_each(|reading| increases.push(reading));
+    increases.count()
+}
+
+/// Counts the increases after each of `smoothings` in one pass over `reader`.
+pub fn stream_smoothed_increases(
+    reader: impl BufRead,
+    smoothings: &[Smoothing],
+    mode: Mode,
+) -> aoc_common::Result<Vec<usize>> {
+    let mut counters: Vec<_> = smoothings
+        .iter()
+        .map(|smoothing| SmoothedIncreases::new(smoothing.filter()))
+        .collect();
+    for reading in read_depths(reader, mode) {
+        let reading = reading?;
+        counters
+            .iter_mut()
+            .for_each(|counter| counter.push(reading));
+    }
+
+    Ok(counters.iter().map(SmoothedIncreases::count).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::ParseError;
+
+    use super::{count_smoothed_increases, Filter, Median, Smoothing};
+
+    const SMALL: [i32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    fn count(smoothing: &str) -> usize {
+        let smoothing: Smoothing = smoothing.parse().unwrap();
+        count_smoothed_increases(SMALL.map(Some), smoothing.filter())
+    }
+
+    #[test]
+    fn moving_sum_and_average_match_part_two() {
+        assert_eq!(count("sum:1"), 7);
+        assert_eq!(count("sum:3"), 5);
+        assert_eq!(count("mean:3"), 5);
+        assert_eq!(count("ema:1"), 7);
+    }
+
+    #[test]
+    fn median_filters_outliers() {
+        let mut median = Median::new(3);
+        let smoothed: Vec<_> = [1, 100, 2, 3, -50, 4]
+            .into_iter()
+            .filter_map(|depth| median.push(depth))
+            .collect();
+        assert_eq!(smoothed, vec![2.0, 3.0, 2.0, 3.0]);
+        assert_eq!(count("median:2"), count("sum:2"));
+        assert_eq!(count(&format!("median:{}", usize::MAX)), 0);
+    }
+
+    #[test]
+    fn exponential_average_lags() {
+        let smoothing: Smoothing = "ema:0.5".parse().unwrap();
+        let mut filter = smoothing.filter();
+        let smoothed: Vec<_> = [8, 0, 0, 8]
+            .into_iter()
+            .filter_map(|depth| filter.push(depth))
+            .collect();
+        assert_eq!(smoothed, vec![8.0, 4.0, 2.0, 5.0]);
+        filter.reset();
+        assert_eq!(filter.push(1), Some(1.0));
+    }
+
+    #[test]
+    fn parse_smoothing() {
+        assert_eq!("median:5".parse(), Ok(Smoothing::Median(5)));
+        assert_eq!(Smoothing::Exponential(0.25).to_string(), "ema:0.25");
+        assert_eq!(
+            "sum:0".parse::<Smoothing>(),
+            Err(ParseError::unexpected_token(
+                "0",
+                "a width of at least 1",
+                5
+            ))
+        );
+        assert!("ema:2".parse::<Smoothing>().is_err());
+        assert!("max:3".parse::<Smoothing>().is_err());
+        assert!("sum".parse::<Smoothing>().is_err());
+    }
+}
@@ -0,0 +1,381 @@
+//! Statistics over a depth series and its window sums.
+
+use std::{
+    fmt::{self, Display},
+    io::BufRead,
+};
+
+use crate::{read_depths, Mode, Reading, Window};
+
+/// A maximal stretch of strictly increasing or decreasing values.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Run {
+    /// Index of the first value of the run.
+    pub start: usize,
+    /// Number of values in the run, at least 2.
+    pub len: usize,
+}
+
+/// A step from one value to the next.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Jump {
+    /// Index of the value the step starts at.
+    pub index: usize,
+    pub from: i64,
+    pub to: i64,
+}
+
+impl Jump {
+    pub fn size(&self) -> i64 {
+        self.to - self.from
+    }
+}
+
+/// Statistics of a non-empty series.
+///
+/// Ties go to the earliest run or jump.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Stats {
+    pub count: usize,
+    pub min: i64,
+    pub max: i64,
+    pub mean: f64,
+    pub longest_increase: Option<Run>,
+    pub longest_decrease: Option<Run>,
+    pub largest_rise: Option<Jump>,
+    pub largest_drop: Option<Jump>,
+    /// Number of maximal stretches of at least two equal values.
+    pub plateaus: usize,
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} values, min {}, max {}, mean {:.2}",
+            self.count, self.min, self.max, self.mean
+        )?;
+        for (name, run) in [
+            ("longest increase", self.longest_increase),
+            ("longest decrease", self.longest_decrease),
+        ] {
+            match run {
+                Some(run) => writeln!(f, "{name}: {} values from index {}", run.len, run.start)?,
+                None => writeln!(f, "{name}: none")?,
+            }
+        }
+        for (name, jump) in [
+            ("largest rise", self.largest_rise),
+            ("largest drop", self.largest_drop),
+        ] {
+            match jump {
+                Some(jump) => writeln!(
+                    f,
+                    "{name}: {:+} from {} to {} at index {}",
+                    jump.size(),
+                    jump.from,
+                    jump.to,
+                    jump.index
+                )?,
+                None => writeln!(f, "{name}: none")?,
+            }
+        }
+        write!(f, "plateaus: {}", self.plateaus)
+    }
+}
+
+/// Collects [`Stats`] one value at a time.
+#[derive(Debug, Clone, Default)]
+pub struct Profile {
+    count: usize,
+    min: i64,
+    max: i64,
+    sum: i128,
+    previous: Option<(usize, i64)>,
+    increase: Option<Run>,
+    decrease: Option<Run>,
+    in_plateau: bool,
+    longest_increase: Option<Run>,
+    longest_decrease: Option<Run>,
+    largest_rise: Option<Jump>,
+    largest_drop: Option<Jump>,
+    plateaus: usize,
+}
+
+impl Profile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `value` at `index` of the series.
+    pub fn push(&mut self, index: usize, value: i64) {
+        if self.count == 0 {
+            (self.min, self.max) = (value, value);
+        }
+        self.count += 1;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.sum += i128::from(value);
+
+        let previous = self.previous.replace((index, value));
+        let Some((start, from)) = previous else {
+            return;
+        };
+        let jump = Jump {
+            index: start,
+            from,
+            to: value,
+        };
+        if value > from {
+            extend(&mut self.increase, start, &mut self.longest_increase);
+            if self
+                .largest_rise
+                .is_none_or(|rise| jump.size() > rise.size())
+            {
+                self.largest_rise = Some(jump);
+            }
+        } else {
+            self.increase = None;
+        }
+        if value < from {
+            extend(&mut self.decrease, start, &mut self.longest_decrease);
+            if self
+                .largest_drop
+                .is_none_or(|drop| jump.size() < drop.size())
+            {
+                self.largest_drop = Some(jump);
+            }
+        } else {
+            self.decrease = None;
+        }
+        if value == from && !self.in_plateau {
+            self.plateaus += 1;
+        }
+        self.in_plateau = value == from;
+    }
+
+    /// Marks a gap, no run, jump or plateau spans it.
+    pub fn gap(&mut self) {
+        self.previous = None;
+        self.increase = None;
+        self.decrease = None;
+        self.in_plateau = false;
+    }
+
+    /// The statistics of everything pushed so far, `None` if that is nothing.
+    pub fn stats(&self) -> Option<Stats> {
+        (self.count > 0).then(|| Stats {
+            count: self.count,
+            min: self.min,
+            max: self.max,
+            mean: self.sum as f64 / self.count as f64,
+            longest_increase: self.longest_increase,
+            longest_decrease: self.longest_decrease,
+            largest_rise: self.largest_rise,
+            largest_drop: self.largest_drop,
+            plateaus: self.plateaus,
+        })
+    }
+}
+
+/// Extends the `current` run by one step from `start`, recording it in
+/// `longest` if it got longer than that.
+fn extend(current: &mut Option<Run>, start: usize, longest: &mut Option<Run>) {
+    let run = current.get_or_insert(Run { start, len: 1 });
+    run.len += 1;
+    if longest.is_none_or(|longest| run.len > longest.len) {
+        *longest = Some(*run);
+    }
+}
+
+/// Sums over a sliding window, keeping only the last `size` values.
+#[derive(Debug, Clone)]
+pub struct WindowSums {
+    window: Window,
+    sum: i64,
+}
+
+impl WindowSums {
+    /// # Panics
+    ///
+    /// If `size` is 0.
+    pub fn new(size: usize) -> Self {
+        Self {
+            window: Window::new(size),
+            sum: 0,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.window.size()
+    }
+
+    /// Adds `depth`, returning the sum of the window it completes.
+    pub fn push(&mut self, depth: i32) -> Option<i64> {
+        self.sum += i64::from(depth);
+        if let Some(leaving) = self.window.push(depth) {
+            self.sum -= i64::from(leaving);
+        }
+        self.window.is_full().then_some(self.sum)
+    }
+
+    /// Marks a missing reading, no window spans it.
+    pub fn gap(&mut self) {
+        self.window.clear();
+        self.sum = 0;
+    }
+}
+
+/// Statistics of the readings and of their sums over windows of a given size.
+///
+/// Windows are indexed by their first reading.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Report {
+    pub window: usize,
+    pub depths: Option<Stats>,
+    pub windows: Option<Stats>,
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sections = [
+            ("Depths".to_string(), &self.depths),
+            (format!("Sums of {} depths", self.window), &self.windows),
+        ];
+        for (idx, (title, stats)) in sections.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "{title}")?;
+            match stats {
+                Some(stats) => writeln!(f, "{stats}")?,
+                None => writeln!(f, "no values")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Profiles readings and their window sums one reading at a time.
+#[derive(Debug, Clone)]
+pub struct Profiler {
+    size: usize,
+    index: usize,
+    depths: Profile,
+    windows: Profile,
+    sums: WindowSums,
+}
+
+impl Profiler {
+    /// # Panics
+    ///
+    /// If `size` is 0.
+    pub fn new(size: usize) -> Self {
+        Self {
+            size,
+            index: 0,
+            depths: Profile::new(),
+            windows: Profile::new(),
+            sums: WindowSums::new(size),
+        }
+    }
+
+    pub fn push(&mut self, reading: Reading) {
+        match reading {
+            Some(depth) => {
+                self.depths.push(self.index, i64::from(depth));
+                if let Some(sum) = self.sums.push(depth) {
+                    self.windows.push(self.index + 1 - self.size, sum);
+                }
+            }
+            None => {
+                self.depths.gap();
+                self.windows.gap();
+                self.sums.gap();
+            }
+        }
+        self.index += 1;
+    }
+
+    pub fn report(&self) -> Report {
+        Report {
+            window: self.size,
+            depths: self.depths.stats(),
+            windows: self.windows.stats(),
+        }
+    }
+}
+
+/// Profiles `readings` and their sums over windows of `size` in one pass.
+///
+/// # Panics
+///
+/// If `size` is 0.
+pub fn profile(readings: impl IntoIterator<Item = Reading>, size: usize) -> Report {
+    let mut profiler = Profiler::new(size);
+    readings
+        .into_iter()
+        .for_each(|reading| profiler.push(reading));
+    profiler.report()
+}
+
+/// Like [`profile`], reading one line at a time from `reader`.
+pub fn stream_profile(reader: impl BufRead, size: usize, mode: Mode) -> aoc_common::Result<Report> {
+    let mut profiler = Profiler::new(size);
+    for reading in read_depths(reader, mode) {
+        profiler.push(reading?);
+    }
+
+    Ok(profiler.report())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{profile, Jump, Run};
+    use crate::parse_readings;
+    use crate::Mode;
+
+    const SMALL: [i32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn profile_of_small_input() {
+        let report = profile(SMALL.map(Some), 3);
+        let depths = report.depths.unwrap();
+        assert_eq!((depths.count, depths.min, depths.max), (10, 199, 269));
+        assert_eq!(depths.mean, 225.6);
+        assert_eq!(depths.longest_increase, Some(Run { start: 0, len: 4 }));
+        assert_eq!(depths.longest_decrease, Some(Run { start: 3, len: 2 }));
+        assert_eq!(
+            depths.largest_rise,
+            Some(Jump {
+                index: 5,
+                from: 207,
+                to: 240
+            })
+        );
+        assert_eq!(depths.largest_drop.unwrap().size(), -10);
+        assert_eq!(depths.plateaus, 0);
+
+        let windows = report.windows.unwrap();
+        assert_eq!((windows.count, windows.min, windows.max), (8, 607, 792));
+        assert_eq!(windows.longest_increase, Some(Run { start: 3, len: 5 }));
+        assert_eq!(windows.largest_rise.unwrap().index, 4);
+        assert_eq!(windows.plateaus, 1);
+    }
+
+    #[test]
+    fn gaps_break_runs_and_windows() {
+        let readings = parse_readings("1\n2\n3\nx\n4\n4\n4", Mode::Lenient).unwrap();
+        let report = profile(readings, 2);
+        let depths = report.depths.unwrap();
+        assert_eq!(depths.longest_increase, Some(Run { start: 0, len: 3 }));
+        assert_eq!(depths.largest_rise.unwrap().size(), 1);
+        assert_eq!(depths.plateaus, 1);
+        let windows = report.windows.unwrap();
+        assert_eq!(windows.count, 4);
+        assert_eq!(windows.longest_increase.unwrap().start, 0);
+        assert_eq!(windows.plateaus, 1);
+        assert!(profile([], 3).depths.is_none());
+    }
+}
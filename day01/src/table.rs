@@ -0,0 +1,399 @@
+//! Sonar dumps with several sensor columns per line.
+//!
+//! Columns are separated by commas if the first line has any, and by
+//! whitespace otherwise. The first line may be a header naming the columns,
+//! see [`Header`].
+
+use std::{
+    convert::Infallible,
+    fmt::{self, Display},
+    io::BufRead,
+    str::FromStr,
+};
+
+use aoc_common::{
+    output::render_table,
+    parse::{fields, number, words},
+    Error, ParseError,
+};
+
+use crate::{Mode, WindowIncreases};
+
+/// A column picked by its header name or by its 1-based position.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Column {
+    Name(String),
+    Index(usize),
+}
+
+/// Numbers are positions, anything else is a name.
+impl FromStr for Column {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse() {
+            Ok(index) if index > 0 => Ok(Column::Index(index)),
+            _ => Ok(Column::Name(s.to_string())),
+        }
+    }
+}
+
+impl Display for Column {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Column::Name(name) => write!(f, "{name}"),
+            Column::Index(index) => write!(f, "{index}"),
+        }
+    }
+}
+
+/// Whether the first line is a header naming the columns.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Header {
+    /// A header if a column is picked by name, or if a column picked by
+    /// position does not hold a number in the first line. Other columns, like
+    /// timestamps, do not matter.
+    #[default]
+    Detect,
+    Present,
+    Absent,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Delimiter {
+    Comma,
+    Whitespace,
+}
+
+impl Delimiter {
+    fn of(line: &str) -> Self {
+        if line.contains(',') {
+            Delimiter::Comma
+        } else {
+            Delimiter::Whitespace
+        }
+    }
+
+    /// The fields of `line`, trimmed and paired with their 1-based column.
+    fn split(self, line: &str) -> Vec<(usize, &str)> {
+        match self {
+            Delimiter::Comma => fields(line, ",")
+                .map(|(column, field)| {
+                    let trimmed = field.trim_start();
+                    (column + field.len() - trimmed.len(), trimmed.trim_end())
+                })
+                .collect(),
+            Delimiter::Whitespace => words(line).collect(),
+        }
+    }
+}
+
+/// The increases of one column, on its own and over windows.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ColumnIncreases {
+    pub name: String,
+    pub increases: usize,
+    pub window_increases: usize,
+}
+
+/// Counts the increases of every one of `columns` on its own and over windows
+/// of `size`, in one pass over `reader`.
+///
+/// In lenient mode malformed or missing fields are gaps in their column only.
+pub fn analyse(
+    mut reader: impl BufRead,
+    columns: &[Column],
+    header: Header,
+    size: usize,
+    mode: Mode,
+) -> aoc_common::Result<Vec<ColumnIncreases>> {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Err(ParseError::unexpected_end("a header or a row", 1).into());
+    }
+    let first = line.trim_end().to_string();
+    let delimiter = Delimiter::of(&first);
+    let first_fields = delimiter.split(&first);
+    let is_row = match header {
+        Header::Present => false,
+        Header::Absent => true,
+        Header::Detect => columns.iter().all(|column| match column {
+            Column::Name(_) => false,
+            Column::Index(index) => first_fields
+                .get(index - 1)
+                .is_some_and(|(_, field)| field.parse::<f64>().is_ok()),
+        }),
+    };
+    let header: Vec<&str> = if is_row {
+        Vec::new()
+    } else {
+        first_fields.iter().map(|&(_, name)| name).collect()
+    };
+
+    let indices = columns
+        .iter()
+        .map(|column| match column {
+            Column::Index(index) if !is_row && *index > header.len() => {
+                Err(Error::usage(format!("no column {index}")))
+            }
+            Column::Index(index) => Ok(index - 1),
+            Column::Name(name) => header
+                .iter()
+                .position(|field| field == name)
+                .ok_or_else(|| Error::usage(format!("no column named `{name}`"))),
+        })
+        .collect::<aoc_common::Result<Vec<usize>>>()?;
+    let width = if is_row {
+        indices.iter().max().map_or(0, |max| max + 1)
+    } else {
+        header.len()
+    };
+
+    let mut counters: Vec<_> = indices
+        .iter()
+        .map(|_| (WindowIncreases::new(1), WindowIncreases::new(size)))
+        .collect();
+    let mut row = |line: &str, line_number: usize| -> Result<(), ParseError> {
+        let fields = delimiter.split(line);
+        for (&index, (single, windows)) in indices.iter().zip(counters.iter_mut()) {
+            let depth = match fields.get(index) {
+                Some(&(column, field)) => number::<i32>(field, column),
+                None => Err(ParseError::WrongFieldCount {
+                    expected: width,
+                    found: fields.len(),
+                    line: 1,
+                    column: line.len() + 1,
+                }),
+            };
+            match (depth, mode) {
+                (Ok(depth), _) => {
+                    single.push(depth);
+                    windows.push(depth);
+                }
+                (Err(_), Mode::Lenient) => {
+                    single.gap();
+                    windows.gap();
+                }
+                (Err(e), Mode::Strict) => return Err(e.offset(line_number - 1, 0)),
+            }
+        }
+        Ok(())
+    };
+
+    let mut line_number = 1;
+    if is_row {
+        row(&first, line_number)?;
+    }
+    // Blank lines only count as rows once a line with content follows, so
+    // blank lines at the end are ignored like after normalising the input.
+    let mut blanks = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        line_number += 1;
+        if line.trim_end().is_empty() {
+            blanks += 1;
+            continue;
+        }
+        for blank in line_number - blanks..line_number {
+            row("", blank)?;
+        }
+        blanks = 0;
+        row(line.trim_end(), line_number)?;
+    }
+
+    Ok(columns
+        .iter()
+        .zip(&indices)
+        .zip(&counters)
+        .map(|((column, &index), (single, windows))| ColumnIncreases {
+            name: header
+                .get(index)
+                .map_or_else(|| column.to_string(), |name| name.to_string()),
+            increases: single.count(),
+            window_increases: windows.count(),
+        })
+        .collect())
+}
+
+/// Renders `results` side by side, one column per sensor.
+pub fn render(results: &[ColumnIncreases], size: usize) -> String {
+    let mut rows = [
+        vec!["Column".to_string()],
+        vec!["Increases".to_string()],
+        vec![format!("Windows of {size}")],
+    ];
+    for result in results {
+        rows[0].push(result.name.clone());
+        rows[1].push(result.increases.to_string());
+        rows[2].push(result.window_increases.to_string());
+    }
+
+    render_table(&rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::ErrorKind;
+
+    use super::{analyse, render, Column, ColumnIncreases, Header};
+    use crate::Mode;
+
+    const CSV: &str = "timestamp, depth_a, depth_b
+2021-12-01T00:00, 199, 5
+2021-12-01T00:01, 200, 4
+2021-12-01T00:02, 208, 3
+2021-12-01T00:03, 210, 6
+";
+
+    fn columns(names: &[&str]) -> Vec<Column> {
+        names.iter().map(|name| name.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn analyse_csv_columns_by_name_and_index() {
+        let actual = analyse(
+            CSV.as_bytes(),
+            &columns(&["depth_a", "3"]),
+            Header::Detect,
+            2,
+            Mode::Strict,
+        );
+        let expected = vec![
+            ColumnIncreases {
+                name: "depth_a".to_string(),
+                increases: 3,
+                window_increases: 2,
+            },
+            ColumnIncreases {
+                name: "depth_b".to_string(),
+                increases: 1,
+                window_increases: 1,
+            },
+        ];
+        assert_eq!(actual.unwrap(), expected);
+
+        let rendered = render(&expected, 2);
+        assert_eq!(
+            rendered,
+            "Column        depth_a  depth_b\nIncreases     3        1\nWindows of 2  2        1"
+        );
+        assert!(analyse(
+            CSV.as_bytes(),
+            &columns(&["depth_c"]),
+            Header::Detect,
+            2,
+            Mode::Strict
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn indices_beyond_the_header_are_unknown_columns() {
+        let error = analyse(
+            CSV.as_bytes(),
+            &columns(&["5"]),
+            Header::Detect,
+            2,
+            Mode::Strict,
+        )
+        .unwrap_err();
+        assert_eq!(error.to_string(), "invalid usage: no column 5");
+        assert!(matches!(error.kind(), ErrorKind::Usage(_)));
+        assert_eq!(error.line(), None);
+    }
+
+    #[test]
+    fn analyse_whitespace_table_without_header() {
+        let table = "1 199 5\n2 200\n3 208 x\n4 210 6\n5 200 7";
+        let error = analyse(
+            table.as_bytes(),
+            &columns(&["2", "3"]),
+            Header::Detect,
+            1,
+            Mode::Strict,
+        )
+        .unwrap_err();
+        assert_eq!((error.line(), error.column()), (Some(2), Some(6)));
+
+        let actual = analyse(
+            table.as_bytes(),
+            &columns(&["2", "3"]),
+            Header::Detect,
+            1,
+            Mode::Lenient,
+        )
+        .unwrap();
+        assert_eq!(actual[0].name, "2");
+        assert_eq!(actual[0].increases, 3);
+        assert_eq!(actual[1].increases, 1);
+    }
+
+    #[test]
+    fn timestamps_do_not_make_a_header() {
+        let dump = "2021-12-01T00:00, 199, 5\n2021-12-01T00:01, 200, 4\n2021-12-01T00:02, 208, 6";
+        let actual = analyse(
+            dump.as_bytes(),
+            &columns(&["2", "3"]),
+            Header::Detect,
+            1,
+            Mode::Strict,
+        )
+        .unwrap();
+        assert_eq!(actual[0].name, "2");
+        assert_eq!((actual[0].increases, actual[1].increases), (2, 1));
+
+        let named = analyse(
+            dump.as_bytes(),
+            &columns(&["a"]),
+            Header::Absent,
+            1,
+            Mode::Strict,
+        );
+        assert!(named.is_err());
+        let numbered = "1,2\n5,6\n7,5";
+        let actual = analyse(
+            numbered.as_bytes(),
+            &columns(&["1", "2"]),
+            Header::Present,
+            1,
+            Mode::Strict,
+        )
+        .unwrap();
+        assert_eq!((actual[0].increases, actual[1].increases), (1, 0));
+    }
+
+    #[test]
+    fn only_trailing_blank_lines_are_ignored() {
+        let pick = |csv: &str| {
+            analyse(
+                csv.as_bytes(),
+                &columns(&["b"]),
+                Header::Detect,
+                1,
+                Mode::Strict,
+            )
+        };
+        assert_eq!(pick("a,b\n1,2\n3,4\n\n \n").unwrap()[0].increases, 1);
+        assert_eq!(pick("a,b\n1,2\n\n3,4\n").unwrap_err().line(), Some(3));
+    }
+
+    #[test]
+    fn strict_reports_field_position() {
+        let csv = "a,b\n1,2\n3, x";
+        let error = analyse(
+            csv.as_bytes(),
+            &columns(&["b"]),
+            Header::Detect,
+            1,
+            Mode::Strict,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "3:4: invalid input: expected a number, found `x`"
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! Exports of the path the submarine takes, for plotting command scripts.
+
+use std::fmt::Write;
+
+use crate::{trace, Direction, Interpretation, Overflow, State};
+
+/// Width and height of the rendered SVG in pixels.
+const SVG_SIZE: (u32, u32) = (800, 400);
+
+/// One row per command with the state after it, after a row for the start.
+pub fn to_csv(
+    commands: &[(Direction, i32)],
+    interpretation: Interpretation,
+) -> Result<String, Overflow> {
+    let mut csv = String::from("step,command,amount,horizontal,depth,aim\n");
+    csv += "0,,,0,0,0\n";
+    for (idx, (command, state)) in commands
+        .iter()
+        .zip(trace(commands, interpretation))
+        .enumerate()
+    {
+        let (direction, amount) = command;
+        let amount = if direction.takes_amount() {
+            amount.to_string()
+        } else {
+            String::new()
+        };
+        let State {
+            horizontal,
+            depth,
+            aim,
+        } = state?;
+        writeln!(
+            csv,
+            "{},{direction},{amount},{horizontal},{depth},{aim}",
+            idx + 1
+        )
+        .expect("writing to a string cannot fail");
+    }
+    Ok(csv)
+}
+
+/// The depth profile as an SVG polyline, horizontal position to the right and
+/// depth downwards.
+pub fn to_svg(
+    commands: &[(Direction, i32)],
+    interpretation: Interpretation,
+) -> Result<String, Overflow> {
+    let states: Vec<State> = std::iter::once(Ok(State::default()))
+        .chain(trace(commands, interpretation))
+        .collect::<Result<_, _>>()?;
+    let (min_x, max_x) = bounds(states.iter().map(|state| state.horizontal));
+    let (min_y, max_y) = bounds(states.iter().map(|state| state.depth));
+    let points: Vec<String> = states
+        .iter()
+        .map(|state| format!("{},{}", state.horizontal, state.depth))
+        .collect();
+
+    let (width, height) = SVG_SIZE;
+    Ok(format!(
+        concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" "#,
+            r#"viewBox="{} {} {} {}" preserveAspectRatio="none">"#,
+            "\n",
+            r#"  <polyline fill="none" stroke="steelblue" stroke-width="2" "#,
+            r#"vector-effect="non-scaling-stroke" points="{}"/>"#,
+            "\n</svg>\n"
+        ),
+        width,
+        height,
+        min_x,
+        min_y,
+        (i128::from(max_x) - i128::from(min_x)).max(1),
+        (i128::from(max_y) - i128::from(min_y)).max(1),
+        points.join(" ")
+    ))
+}
+
+fn bounds(values: impl Iterator<Item = i64>) -> (i64, i64) {
+    values.fold((0, 0), |(min, max), value| (min.min(value), max.max(value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_csv, to_svg};
+    use crate::{parse_input, Interpretation};
+
+    #[test]
+    fn csv_has_a_row_per_command() {
+        let commands = parse_input("forward 5\ndown 5\nforward 8\nlevel").unwrap();
+        let csv = to_csv(&commands, Interpretation::Aim).unwrap();
+        let expected = "step,command,amount,horizontal,depth,aim
+0,,,0,0,0
+1,forward,5,5,0,0
+2,down,5,5,0,5
+3,forward,8,13,40,5
+4,level,,13,40,0
+";
+        assert_eq!(csv, expected);
+    }
+
+    #[test]
+    fn svg_spans_the_trajectory() {
+        let commands = parse_input("forward 5\ndown 5\nforward 8\nup 10").unwrap();
+        let svg = to_svg(&commands, Interpretation::Direct).unwrap();
+        assert!(svg.contains(r#"viewBox="0 -5 13 10""#));
+        assert!(svg.contains(r#"points="0,0 5,0 5,5 13,5 13,-5""#));
+    }
+}
@@ -0,0 +1,400 @@
+//! Day 2: Dive!
+//!
+//! Follows a list of submarine commands to find its final position.
+
+use aoc_common::{
+    parse::{number, parse_lines, words},
+    Error, ParseError, Solution,
+};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+pub mod export;
+pub mod script;
+pub mod synth;
+
+/// The keyword of a submarine command.
+///
+/// Under the first interpretation `up` and `down` change the depth directly,
+/// so `level` and `set-aim`, which set the aim under the second one, set the
+/// depth instead.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Direction {
+    Forward,
+    Up,
+    Down,
+    /// Moves backwards, the opposite of `forward`.
+    Back,
+    /// Resets the aim to 0, takes no amount.
+    Level,
+    /// Sets the aim to the amount.
+    SetAim,
+}
+
+impl Direction {
+    /// Whether the command is followed by an amount.
+    pub fn takes_amount(&self) -> bool {
+        *self != Direction::Level
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let keyword = match self {
+            Direction::Forward => "forward",
+            Direction::Up => "up",
+            Direction::Down => "down",
+            Direction::Back => "back",
+            Direction::Level => "level",
+            Direction::SetAim => "set-aim",
+        };
+        write!(f, "{keyword}")
+    }
+}
+
+const EXPECTED_DIRECTION: &str = "one of `forward`, `down`, `up`, `back`, `level` or `set-aim`";
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "forward" => Direction::Forward,
+            "up" => Direction::Up,
+            "down" => Direction::Down,
+            "back" => Direction::Back,
+            "level" => Direction::Level,
+            "set-aim" => Direction::SetAim,
+            "" => return Err(ParseError::unexpected_end(EXPECTED_DIRECTION, 1)),
+            _ => return Err(ParseError::unexpected_token(s, EXPECTED_DIRECTION, 1)),
+        })
+    }
+}
+
+/// [`Solution`] for day 2.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<(Direction, i32)>;
+    type PartOne = i128;
+    type PartTwo = i128;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> aoc_common::Result<Self::PartOne> {
+        solve_1(input).map_err(Overflow::into_error)
+    }
+
+    fn part_two(input: &Self::Input) -> aoc_common::Result<Self::PartTwo> {
+        solve_2(input).map_err(Overflow::into_error)
+    }
+}
+
+/// Product of horizontal position and depth when `up` and `down` change the
+/// depth directly.
+pub fn solve_1(input: &[(Direction, i32)]) -> Result<i128, Overflow> {
+    Ok(destination(input, Interpretation::Direct)?.product())
+}
+
+/// Product of horizontal position and depth when `up` and `down` change the
+/// aim.
+pub fn solve_2(input: &[(Direction, i32)]) -> Result<i128, Overflow> {
+    Ok(destination(input, Interpretation::Aim)?.product())
+}
+
+/// How the commands move the submarine.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Interpretation {
+    /// Part one, `up` and `down` change the depth.
+    Direct,
+    /// Part two, `up` and `down` change the aim and `forward` dives along it.
+    Aim,
+}
+
+const HORIZONTAL: &str = "horizontal position";
+const DEPTH: &str = "depth";
+const AIM: &str = "aim";
+
+/// Where the submarine is and where it is heading.
+///
+/// The aim stays 0 under [`Interpretation::Direct`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct State {
+    pub horizontal: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+impl State {
+    /// The state after following `command`.
+    ///
+    /// Fails with the name of the quantity that would overflow.
+    pub fn apply(
+        self,
+        &(direction, amount): &(Direction, i32),
+        interpretation: Interpretation,
+    ) -> Result<Self, &'static str> {
+        let amount = i64::from(amount);
+        let checked = |value: Option<i64>, quantity| value.ok_or(quantity);
+        let State {
+            horizontal,
+            depth,
+            aim,
+        } = self;
+        let mut next = self;
+        match (interpretation, direction) {
+            (_, Direction::Forward) => {
+                next.horizontal = checked(horizontal.checked_add(amount), HORIZONTAL)?;
+                let dive = aim.checked_mul(amount);
+                next.depth = checked(dive.and_then(|dive| depth.checked_add(dive)), DEPTH)?;
+            }
+            (_, Direction::Back) => {
+                next.horizontal = checked(horizontal.checked_sub(amount), HORIZONTAL)?;
+                let dive = aim.checked_mul(amount);
+                next.depth = checked(dive.and_then(|dive| depth.checked_sub(dive)), DEPTH)?;
+            }
+            (Interpretation::Direct, Direction::Up) => {
+                next.depth = checked(depth.checked_sub(amount), DEPTH)?;
+            }
+            (Interpretation::Direct, Direction::Down) => {
+                next.depth = checked(depth.checked_add(amount), DEPTH)?;
+            }
+            (Interpretation::Direct, Direction::Level) => next.depth = 0,
+            (Interpretation::Direct, Direction::SetAim) => next.depth = amount,
+            (Interpretation::Aim, Direction::Up) => {
+                next.aim = checked(aim.checked_sub(amount), AIM)?;
+            }
+            (Interpretation::Aim, Direction::Down) => {
+                next.aim = checked(aim.checked_add(amount), AIM)?;
+            }
+            (Interpretation::Aim, Direction::Level) => next.aim = 0,
+            (Interpretation::Aim, Direction::SetAim) => next.aim = amount,
+        }
+
+        Ok(next)
+    }
+
+    /// Product of horizontal position and depth, which cannot overflow.
+    pub fn product(&self) -> i128 {
+        i128::from(self.horizontal) * i128::from(self.depth)
+    }
+}
+
+/// A command that would move the submarine beyond what [`State`] can hold.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Overflow {
+    /// 0-based index of the command.
+    pub index: usize,
+    pub command: (Direction, i32),
+    /// The quantity that would overflow, e.g. `depth`.
+    pub quantity: &'static str,
+}
+
+impl Overflow {
+    /// Converts the overflow into an error on the command's line of a plain
+    /// command list.
+    pub fn into_error(self) -> Error {
+        Error::unsolvable(self).at_line(self.index + 1)
+    }
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (direction, amount) = self.command;
+        write!(f, "command {} `{direction}", self.index + 1)?;
+        if direction.takes_amount() {
+            write!(f, " {amount}")?;
+        }
+        write!(f, "` overflows the {}", self.quantity)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// The state after every command, starting from the surface.
+///
+/// Ends after the first command that overflows.
+pub fn trace(
+    commands: &[(Direction, i32)],
+    interpretation: Interpretation,
+) -> impl Iterator<Item = Result<State, Overflow>> + '_ {
+    let mut state = Some(State::default());
+    commands
+        .iter()
+        .enumerate()
+        .map_while(move |(index, command)| {
+            let next = state?
+                .apply(command, interpretation)
+                .map_err(|quantity| Overflow {
+                    index,
+                    command: *command,
+                    quantity,
+                });
+            state = next.ok();
+            Some(next)
+        })
+}
+
+/// The state after all commands.
+pub fn destination(
+    commands: &[(Direction, i32)],
+    interpretation: Interpretation,
+) -> Result<State, Overflow> {
+    trace(commands, interpretation)
+        .last()
+        .unwrap_or(Ok(State::default()))
+}
+
+/// Parses one `<direction> <amount>` command per line, or `level` on its own.
+pub fn parse_input(s: &str) -> Result<Vec<(Direction, i32)>, ParseError> {
+    parse_lines(s, parse_command)
+}
+
+/// Parses a single command, `level` gets an amount of 0.
+pub fn parse_command(line: &str) -> Result<(Direction, i32), ParseError> {
+    let mut words = words(line);
+    let end = line.len() + 1;
+    let (column, keyword) = words
+        .next()
+        .ok_or_else(|| ParseError::unexpected_end(EXPECTED_DIRECTION, end))?;
+    let direction: Direction = keyword
+        .parse()
+        .map_err(|e: ParseError| e.offset(0, column - 1))?;
+    let amount = if direction.takes_amount() {
+        let (column, amount) = words
+            .next()
+            .ok_or_else(|| ParseError::unexpected_end("an amount", end))?;
+        number(amount, column)?
+    } else {
+        0
+    };
+    match words.next() {
+        Some((column, extra)) => Err(ParseError::unexpected_token(extra, "end of line", column)),
+        None => Ok((direction, amount)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use aoc_common::ParseError;
+
+    use super::{parse_input, solve_1, solve_2, trace, Direction, Interpretation, Overflow, State};
+
+    #[test]
+    fn parse_input_works() {
+        let content = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2";
+        let actual = parse_input(content).unwrap();
+        let expected: Vec<(Direction, i32)> = vec![
+            (Direction::Forward, 5),
+            (Direction::Down, 5),
+            (Direction::Forward, 8),
+            (Direction::Up, 3),
+            (Direction::Down, 8),
+            (Direction::Forward, 2),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn small_input_solved_1() {
+        let content = fs::read_to_string("small_input.txt").unwrap();
+        let actual = solve_1(&parse_input(content.as_str()).unwrap()).unwrap();
+        let expected = 150;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn small_input_solved_2() {
+        let content = fs::read_to_string("small_input.txt").unwrap();
+        let actual = solve_2(&parse_input(content.as_str()).unwrap()).unwrap();
+        let expected = 900;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn parse_input_rejects_unknown_keywords() {
+        let expected = ParseError::UnexpectedToken {
+            token: "fubar".to_string(),
+            expected: "one of `forward`, `down`, `up`, `back`, `level` or `set-aim`",
+            line: 2,
+            column: 2,
+        };
+        assert_eq!(parse_input("up 1\n fubar 3"), Err(expected));
+        assert_eq!(
+            parse_input("level 3").unwrap_err(),
+            ParseError::unexpected_token("3", "end of line", 7)
+        );
+        assert_eq!(
+            parse_input("forward 1\nset-aim").unwrap_err(),
+            ParseError::unexpected_end("an amount", 8).offset(1, 0)
+        );
+    }
+
+    #[test]
+    fn extended_commands() {
+        let commands = parse_input("set-aim 2\nforward 5\nback 2\nlevel\nforward 3").unwrap();
+        assert_eq!(commands[3], (Direction::Level, 0));
+        // Depth is set to 2 and then to 0, the horizontal position ends at 6.
+        assert_eq!(solve_1(&commands), Ok(0));
+        // Depth is 2 * 5 - 2 * 2 = 6 and not changed by the last forward.
+        assert_eq!(solve_2(&commands), Ok(36));
+    }
+
+    #[test]
+    fn trace_small_input() {
+        let content = fs::read_to_string("small_input.txt").unwrap();
+        let commands = parse_input(&content).unwrap();
+        let states: Vec<State> = trace(&commands, Interpretation::Aim)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(states.len(), 6);
+        assert_eq!(
+            states[2],
+            State {
+                horizontal: 13,
+                depth: 40,
+                aim: 5
+            }
+        );
+        let last = trace(&commands, Interpretation::Direct)
+            .last()
+            .unwrap()
+            .unwrap();
+        assert_eq!((last.horizontal, last.depth, last.aim), (15, 10, 0));
+    }
+
+    #[test]
+    fn overflow_names_the_command() {
+        let commands = vec![(Direction::Down, 1_000_000), (Direction::Forward, 100_000)];
+        assert_eq!(solve_2(&commands), Ok(10_000_000_000_000_000));
+
+        // Every forward dives by (2^31 - 1)^2, just under 2^62, so the third one
+        // overflows.
+        let commands = vec![
+            (Direction::SetAim, i32::MAX),
+            (Direction::Forward, i32::MAX),
+            (Direction::Forward, i32::MAX),
+            (Direction::Forward, i32::MAX),
+            (Direction::Forward, i32::MAX),
+        ];
+        let expected = Overflow {
+            index: 3,
+            command: (Direction::Forward, i32::MAX),
+            quantity: "depth",
+        };
+        assert_eq!(solve_2(&commands), Err(expected));
+        assert_eq!(
+            expected.to_string(),
+            "command 4 `forward 2147483647` overflows the depth"
+        );
+        assert_eq!(trace(&commands, Interpretation::Aim).count(), 4);
+    }
+}
@@ -1,112 +1,94 @@
-use std::{fs, str::FromStr};
+use std::process::ExitCode;
 
-fn main() {
-    let content = fs::read_to_string("input.txt").unwrap();
-    let v = parse_input(content.as_str());
-    println!("Part 1: {}", solve_1(&v));
-    println!("Part 2: {}", solve_2(&v));
-}
-
-#[derive(Debug, PartialEq)]
-enum Direction {
-    Forward,
-    Up,
-    Down,
-}
-
-impl FromStr for Direction {
-    type Err = &'static str;
+use aoc_common::{cli, Error, Solution};
+use clap::{Parser, ValueEnum};
+use day02::{
+    export, parse_input, script, solve_1, solve_2, synth, Day02, Interpretation, Overflow,
+};
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Some(first) = s.chars().next() {
-            Ok(match first {
-                'f' => Direction::Forward,
-                'u' => Direction::Up,
-                'd' => Direction::Down,
-                _ => return Err("unexpected input"),
-            })
-        } else {
-            Err("unexpected input")
-        }
-    }
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Trace {
+    Csv,
+    Svg,
 }
 
-fn solve_1(input: &Vec<(Direction, i32)>) -> i32 {
-    let (h, d) =
-        input.iter().fold(
-            (0, 0),
-            |(horizontal, depth), (direction, amount)| match direction {
-                Direction::Forward => (horizontal + amount, depth),
-                Direction::Up => (horizontal, depth - amount),
-                Direction::Down => (horizontal, depth + amount),
-            },
-        );
-    h * d
+#[derive(Debug, Parser)]
+#[command(about = "Solve both parts of Advent of Code 2021 day 2")]
+struct Cli {
+    #[command(flatten)]
+    common: cli::Args,
+    /// Print the state after every command instead of the answers.
+    #[arg(short, long, value_enum, conflicts_with = "format")]
+    trace: Option<Trace>,
+    /// Trace or reach a target the way part 1 or part 2 reads the commands.
+    #[arg(short, long, default_value_t = 2, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Read the input as a script with `repeat`, `macro` and `let`.
+    #[arg(long, conflicts_with = "format")]
+    script: bool,
+    /// Print a shortest command list that ends at `<horizontal>,<depth>`.
+    #[arg(long, value_name = "TARGET", value_parser = synth::parse_target, allow_hyphen_values = true, conflicts_with_all = ["format", "trace", "script"])]
+    reach: Option<(i64, i64)>,
+    /// Give up on targets that need more commands than this.
+    #[arg(long, default_value_t = 100, requires = "reach")]
+    max_commands: usize,
 }
 
-fn solve_2(input: &Vec<(Direction, i32)>) -> i32 {
-    // down X increases your aim by X units.
-    // up X decreases your aim by X units.
-    // forward X does two things:
-    // It increases your horizontal position by X units.
-    // It increases your depth by your aim multiplied by X.
-    let (h, d, _) = input.iter().fold(
-        (0, 0, 0),
-        |(horizontal, depth, aim), (direction, amount)| match direction {
-            Direction::Forward => (horizontal + amount, depth + aim * amount, aim),
-            Direction::Up => (horizontal, depth, aim - amount),
-            Direction::Down => (horizontal, depth, aim + amount),
-        },
-    );
-    h * d
-}
-
-fn parse_input(s: &str) -> Vec<(Direction, i32)> {
-    s.lines()
-        .filter_map(|line| {
-            let mut split = line.split_whitespace();
-            split
-                .next()
-                .and_then(|d| Direction::from_str(d).ok())
-                .zip(split.next().and_then(|n| n.parse::<i32>().ok()))
-        })
-        .collect()
-}
-
-#[cfg(test)]
-mod tests {
-    use std::fs;
-
-    use super::{parse_input, solve_1, solve_2, Direction};
-
-    #[test]
-    fn parse_input_works() {
-        let content = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2";
-        let actual = parse_input(content);
-        let expected: Vec<(Direction, i32)> = vec![
-            (Direction::Forward, 5),
-            (Direction::Down, 5),
-            (Direction::Forward, 8),
-            (Direction::Up, 3),
-            (Direction::Down, 8),
-            (Direction::Forward, 2),
-        ];
-        assert_eq!(actual, expected);
+fn main() -> ExitCode {
+    let args = Cli::parse();
+    let interpretation = match args.part {
+        1 => Interpretation::Direct,
+        _ => Interpretation::Aim,
+    };
+    if let Some(target) = args.reach {
+        return match synth::synthesise(target, interpretation, args.max_commands) {
+            Ok(commands) => {
+                for (direction, amount) in commands {
+                    if direction.takes_amount() {
+                        println!("{direction} {amount}");
+                    } else {
+                        println!("{direction}");
+                    }
+                }
+                ExitCode::SUCCESS
+            }
+            Err(e) => aoc_common::report(Error::unsolvable(e)),
+        };
     }
 
-    #[test]
-    fn small_input_solved_1() {
-        let content = fs::read_to_string("small_input.txt").unwrap();
-        let actual = solve_1(&parse_input(content.as_str()));
-        let expected = 150;
-        assert_eq!(actual, expected);
+    let source = args.common.source(Day02::DAY);
+    if args.trace.is_none() && !args.script {
+        return match aoc_common::run::<Day02>(&source, args.common.format()) {
+            Ok(_) => ExitCode::SUCCESS,
+            Err(e) => aoc_common::report(e),
+        };
     }
 
-    #[test]
-    fn small_input_solved_2() {
-        let content = fs::read_to_string("small_input.txt").unwrap();
-        let actual = solve_2(&parse_input(content.as_str()));
-        let expected = 900;
-        assert_eq!(actual, expected);
+    let output = source.load().and_then(|content| {
+        let (commands, overflow): (_, fn(Overflow) -> Error) = if args.script {
+            (script::expand(&content), Error::unsolvable)
+        } else {
+            (
+                parse_input(&content).map_err(Error::from),
+                Overflow::into_error,
+            )
+        };
+        let commands = commands.map_err(|e| source.annotate(e))?;
+        let output = match args.trace {
+            Some(Trace::Csv) => export::to_csv(&commands, interpretation),
+            Some(Trace::Svg) => export::to_svg(&commands, interpretation),
+            None => solve_1(&commands).and_then(|part_one| {
+                let part_two = solve_2(&commands)?;
+                Ok(format!("Part One: {part_one}\nPart Two: {part_two}\n"))
+            }),
+        };
+        output.map_err(|e| source.annotate(overflow(e)))
+    });
+    match output {
+        Ok(output) => {
+            print!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => aoc_common::report(e),
     }
 }
@@ -0,0 +1,530 @@
+//! A small scripting language that expands to plain commands.
+//!
+//! ```text
+//! # Comments run to the end of the line.
+//! let dive = 5
+//! macro zigzag {
+//!     down dive
+//!     forward 2
+//!     up dive
+//! }
+//! repeat 3 {
+//!     zigzag
+//!     forward 1
+//! }
+//! level
+//! ```
+//!
+//! Every statement is on its own line: a command, `let <name> = <value>`,
+//! `macro <name> { ... }`, `repeat <value> { ... }` or the name of a macro.
+//! Values are numbers or variables. Macros see the variables as they are when
+//! the macro is called, and a plain list of commands is a valid script.
+
+use std::collections::HashMap;
+
+use aoc_common::{parse::number, Error, ParseError};
+
+use crate::Direction;
+
+/// Most commands a script may expand to.
+pub const MAX_COMMANDS: usize = 10_000_000;
+
+/// Most commands, macro calls and `repeat` iterations a script may run, so
+/// loops that expand to nothing end too.
+pub const MAX_STEPS: usize = 50_000_000;
+
+/// Most blocks and macro calls that may be open at once, so deep nesting is
+/// an error rather than a stack overflow. Every level takes a few kilobytes
+/// of stack in debug builds, and this stays well within the 2 MiB of a
+/// spawned thread.
+pub const MAX_NESTING: usize = 100;
+
+const KEYWORDS: [&str; 3] = ["let", "macro", "repeat"];
+const EXPECTED_STATEMENT: &str = "a command, `let`, `macro`, `repeat` or a macro name";
+const EXPECTED_VALUE: &str = "a number or a variable";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Kind {
+    Word(String),
+    Number(String),
+    Open,
+    Close,
+    Equals,
+    Newline,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Token {
+    kind: Kind,
+    line: usize,
+    column: usize,
+}
+
+impl Token {
+    fn text(&self) -> &str {
+        match &self.kind {
+            Kind::Word(text) | Kind::Number(text) => text,
+            Kind::Open => "{",
+            Kind::Close => "}",
+            Kind::Equals => "=",
+            Kind::Newline => "",
+        }
+    }
+
+    fn unexpected(&self, expected: &'static str) -> ParseError {
+        match self.kind {
+            Kind::Newline => ParseError::UnexpectedEnd {
+                expected,
+                line: self.line,
+                column: self.column,
+            },
+            _ => ParseError::UnexpectedToken {
+                token: self.text().to_string(),
+                expected,
+                line: self.line,
+                column: self.column,
+            },
+        }
+    }
+}
+
+/// Splits `s` into tokens, with a newline token at the end of every line.
+fn tokenize(s: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = Vec::new();
+    for (idx, line) in s.lines().enumerate() {
+        let mut chars = line.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            let token = |kind| Token {
+                kind,
+                line: idx + 1,
+                column: start + 1,
+            };
+            match c {
+                '#' => break,
+                '{' => tokens.push(token(Kind::Open)),
+                '}' => tokens.push(token(Kind::Close)),
+                '=' => tokens.push(token(Kind::Equals)),
+                c if c.is_whitespace() => {}
+                c if is_word_char(c) => {
+                    let mut end = start + c.len_utf8();
+                    while let Some(&(idx, c)) = chars.peek() {
+                        if !is_word_char(c) {
+                            break;
+                        }
+                        end = idx + c.len_utf8();
+                        chars.next();
+                    }
+                    let text = line[start..end].to_string();
+                    if c.is_ascii_digit() || c == '-' {
+                        tokens.push(token(Kind::Number(text)));
+                    } else {
+                        tokens.push(token(Kind::Word(text)));
+                    }
+                }
+                c => {
+                    return Err(token(Kind::Word(c.to_string()))
+                        .unexpected("a word, a number, `{`, `}` or `=`"))
+                }
+            }
+        }
+        tokens.push(Token {
+            kind: Kind::Newline,
+            line: idx + 1,
+            column: line.len() + 1,
+        });
+    }
+
+    Ok(tokens)
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Value {
+    Number(i32),
+    Variable(Token),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Statement {
+    Command(Token, Direction, Option<Value>),
+    Let(String, Value),
+    Macro(String, Vec<Statement>),
+    Repeat(Token, Value, Vec<Statement>),
+    Call(Token),
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    /// Blocks open at the current position.
+    depth: usize,
+}
+
+/// The error for the block or macro call at `token` that nests too deep.
+fn too_deep(token: &Token) -> Error {
+    let message = format!("blocks and macro calls nest deeper than {MAX_NESTING} levels");
+    Error::unsolvable(message).at(token.line, token.column)
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    /// The next token, input always ends with a newline token.
+    fn next(&mut self) -> &Token {
+        let token = &self.tokens[self.position.min(self.tokens.len() - 1)];
+        self.position += 1;
+        token
+    }
+
+    /// Statements up to the end of input or, in a block, up to its `}`.
+    fn block(&mut self, open: Option<&Token>) -> aoc_common::Result<Vec<Statement>> {
+        let mut statements = Vec::new();
+        loop {
+            match (self.peek().map(|token| &token.kind), open) {
+                (None, None) => return Ok(statements),
+                (None, Some(open)) => {
+                    return Err(ParseError::UnexpectedEnd {
+                        expected: "`}` to close the block",
+                        line: open.line,
+                        column: open.column,
+                    }
+                    .into())
+                }
+                (Some(Kind::Newline), _) => self.position += 1,
+                (Some(Kind::Close), Some(_)) => {
+                    self.position += 1;
+                    return Ok(statements);
+                }
+                _ => {
+                    statements.push(self.statement()?);
+                    match self.peek() {
+                        Some(token) if token.kind == Kind::Close && open.is_some() => {}
+                        Some(token) if token.kind != Kind::Newline => {
+                            return Err(token.unexpected("end of line").into())
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+
+    /// The statements of the block opened by `open`.
+    fn nested(&mut self, open: &Token) -> aoc_common::Result<Vec<Statement>> {
+        if self.depth == MAX_NESTING {
+            return Err(too_deep(open));
+        }
+        self.depth += 1;
+        let block = self.block(Some(open));
+        self.depth -= 1;
+        block
+    }
+
+    fn statement(&mut self) -> aoc_common::Result<Statement> {
+        let token = self.next().clone();
+        let Kind::Word(word) = &token.kind else {
+            return Err(token.unexpected(EXPECTED_STATEMENT).into());
+        };
+        match word.as_str() {
+            "let" => {
+                let name = self.name()?;
+                self.expect(Kind::Equals, "`=`")?;
+                Ok(Statement::Let(name, self.value()?))
+            }
+            "macro" => {
+                let name = self.name()?;
+                let open = self.expect(Kind::Open, "`{`")?;
+                Ok(Statement::Macro(name, self.nested(&open)?))
+            }
+            "repeat" => {
+                let count = self.value()?;
+                let open = self.expect(Kind::Open, "`{`")?;
+                Ok(Statement::Repeat(token, count, self.nested(&open)?))
+            }
+            word => match word.parse::<Direction>() {
+                Ok(direction) if direction.takes_amount() => {
+                    let amount = self.value()?;
+                    Ok(Statement::Command(token, direction, Some(amount)))
+                }
+                Ok(direction) => Ok(Statement::Command(token, direction, None)),
+                Err(_) => Ok(Statement::Call(token)),
+            },
+        }
+    }
+
+    fn expect(&mut self, kind: Kind, expected: &'static str) -> Result<Token, ParseError> {
+        let token = self.next();
+        if token.kind == kind {
+            Ok(token.clone())
+        } else {
+            Err(token.unexpected(expected))
+        }
+    }
+
+    /// A name for a variable or macro, which must not be a keyword.
+    fn name(&mut self) -> Result<String, ParseError> {
+        let token = self.next();
+        match &token.kind {
+            Kind::Word(word) if !is_reserved(word) => Ok(word.clone()),
+            _ => Err(token.unexpected("a name that is not a keyword")),
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, ParseError> {
+        let token = self.next();
+        match &token.kind {
+            Kind::Number(text) => number(text, token.column)
+                .map(Value::Number)
+                .map_err(|e| e.offset(token.line - 1, 0)),
+            Kind::Word(word) if !is_reserved(word) => Ok(Value::Variable(token.clone())),
+            _ => Err(token.unexpected(EXPECTED_VALUE)),
+        }
+    }
+}
+
+fn is_reserved(word: &str) -> bool {
+    KEYWORDS.contains(&word) || word.parse::<Direction>().is_ok()
+}
+
+/// Runs statements, collecting the commands they expand to.
+struct Expander<'a> {
+    variables: HashMap<&'a str, i32>,
+    macros: HashMap<&'a str, &'a [Statement]>,
+    calls: Vec<&'a str>,
+    commands: Vec<(Direction, i32)>,
+    /// Loops and macro calls running at the current statement.
+    depth: usize,
+    /// Most steps the script may take, see [`MAX_STEPS`].
+    max_steps: usize,
+    steps: usize,
+}
+
+impl<'a> Expander<'a> {
+    fn new(max_steps: usize) -> Self {
+        Self {
+            variables: HashMap::new(),
+            macros: HashMap::new(),
+            calls: Vec::new(),
+            commands: Vec::new(),
+            depth: 0,
+            max_steps,
+            steps: 0,
+        }
+    }
+
+    /// Counts a step taken at `token`.
+    fn step(&mut self, token: &Token) -> aoc_common::Result<()> {
+        if self.steps == self.max_steps {
+            let message = format!("the script runs more than {} steps", self.max_steps);
+            return Err(Error::unsolvable(message).at(token.line, token.column));
+        }
+        self.steps += 1;
+        Ok(())
+    }
+
+    /// Runs `body` of the loop or macro call at `token`.
+    fn nested(&mut self, token: &Token, body: &'a [Statement]) -> aoc_common::Result<()> {
+        if self.depth == MAX_NESTING {
+            return Err(too_deep(token));
+        }
+        self.depth += 1;
+        let result = self.run(body);
+        self.depth -= 1;
+        result
+    }
+
+    fn run(&mut self, statements: &'a [Statement]) -> aoc_common::Result<()> {
+        for statement in statements {
+            match statement {
+                Statement::Command(token, direction, amount) => {
+                    if self.commands.len() == MAX_COMMANDS {
+                        let message =
+                            format!("the script expands to more than {MAX_COMMANDS} commands");
+                        return Err(Error::unsolvable(message).at(token.line, token.column));
+                    }
+                    self.step(token)?;
+                    let amount = match amount {
+                        Some(amount) => self.value(amount)?,
+                        None => 0,
+                    };
+                    self.commands.push((*direction, amount));
+                }
+                Statement::Let(name, value) => {
+                    let value = self.value(value)?;
+                    self.variables.insert(name, value);
+                }
+                Statement::Macro(name, body) => {
+                    self.macros.insert(name, body);
+                }
+                Statement::Repeat(token, count, body) => {
+                    let count = self.value(count)?;
+                    if count < 0 {
+                        return Err(token.unexpected("a repeat count of at least 0").into());
+                    }
+                    for _ in 0..count {
+                        self.step(token)?;
+                        self.nested(token, body)?;
+                    }
+                }
+                Statement::Call(token) => {
+                    let name = token.text();
+                    let Some(body) = self.macros.get(name).copied() else {
+                        return Err(token.unexpected(EXPECTED_STATEMENT).into());
+                    };
+                    if self.calls.contains(&name) {
+                        return Err(token.unexpected("a macro that does not call itself").into());
+                    }
+                    self.step(token)?;
+                    self.calls.push(name);
+                    self.nested(token, body)?;
+                    self.calls.pop();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn value(&self, value: &Value) -> Result<i32, ParseError> {
+        match value {
+            Value::Number(n) => Ok(*n),
+            Value::Variable(token) => self
+                .variables
+                .get(token.text())
+                .copied()
+                .ok_or_else(|| token.unexpected("a variable set with `let`")),
+        }
+    }
+}
+
+/// Expands `script` to the plain commands it stands for.
+///
+/// Fails on malformed scripts, and on scripts that expand to more than
+/// [`MAX_COMMANDS`] commands, take more than [`MAX_STEPS`] steps or nest
+/// blocks and macro calls deeper than [`MAX_NESTING`] levels.
+pub fn expand(script: &str) -> aoc_common::Result<Vec<(Direction, i32)>> {
+    expand_within(script, MAX_STEPS)
+}
+
+fn expand_within(script: &str, max_steps: usize) -> aoc_common::Result<Vec<(Direction, i32)>> {
+    let mut parser = Parser {
+        tokens: tokenize(script)?,
+        position: 0,
+        depth: 0,
+    };
+    let statements = parser.block(None)?;
+    let mut expander = Expander::new(max_steps);
+    expander.run(&statements)?;
+    Ok(expander.commands)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expand, expand_within, MAX_NESTING};
+    use crate::{parse_input, Direction};
+
+    #[test]
+    fn plain_commands_are_a_script() {
+        let content = std::fs::read_to_string("small_input.txt").unwrap();
+        assert_eq!(expand(&content).unwrap(), parse_input(&content).unwrap());
+    }
+
+    #[test]
+    fn expand_macros_loops_and_variables() {
+        let script = "
+# Dive in steps.
+let dive = 5
+macro zigzag {
+    down dive  # uses the value at the call
+    forward 2
+}
+repeat 2 { zigzag }
+let dive = -1
+repeat 0 {
+    back 100
+}
+zigzag
+level
+";
+        let expected = vec![
+            (Direction::Down, 5),
+            (Direction::Forward, 2),
+            (Direction::Down, 5),
+            (Direction::Forward, 2),
+            (Direction::Down, -1),
+            (Direction::Forward, 2),
+            (Direction::Level, 0),
+        ];
+        assert_eq!(expand(script).unwrap(), expected);
+    }
+
+    #[test]
+    fn errors_know_line_and_column() {
+        let error = |script| {
+            let error = expand(script).unwrap_err();
+            (error.line(), error.column(), error.to_string())
+        };
+        assert_eq!(
+            error("forward 1\nrepeat 2 {\n  down x\n}"),
+            (
+                Some(3),
+                Some(8),
+                "3:8: invalid input: expected a variable set with `let`, found `x`".to_string()
+            )
+        );
+        assert_eq!(
+            error("repeat 2 {\n  up 1"),
+            (
+                Some(1),
+                Some(10),
+                "1:10: invalid input: expected `}` to close the block, found end of input"
+                    .to_string()
+            )
+        );
+        assert_eq!(error("macro m {\n  m\n}\nm").0, Some(2));
+        assert_eq!(error("let forward = 1").1, Some(5));
+        assert_eq!(error("up 1 2").1, Some(6));
+        assert_eq!(error("up\n").1, Some(3));
+        assert_eq!(error("up 1 ; down 2").1, Some(6));
+        assert_eq!(error("dive 3").1, Some(6));
+        assert_eq!(error("dive").1, Some(1));
+    }
+
+    #[test]
+    fn nesting_is_limited() {
+        let nest = |depth| "repeat 1 {\n".repeat(depth) + "level\n" + &"}\n".repeat(depth);
+        assert_eq!(expand(&nest(MAX_NESTING)).unwrap().len(), 1);
+        let error = expand(&nest(MAX_NESTING + 1)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "101:10: no solution: blocks and macro calls nest deeper than 100 levels"
+        );
+        assert!(expand(&nest(200_000)).is_err());
+
+        // Every macro body is shallow, but the calls nest.
+        let mut script = "macro m0 { level }\n".to_string();
+        for idx in 1..=MAX_NESTING {
+            script += &format!("macro m{idx} {{ m{} }}\n", idx - 1);
+        }
+        script += &format!("m{MAX_NESTING}");
+        let error = expand(&script).unwrap_err();
+        assert_eq!((error.line(), error.column()), (Some(2), Some(12)));
+    }
+
+    #[test]
+    fn loops_without_commands_end() {
+        let script = "repeat 2000000000 {\n  repeat 2000000000 { let x = 1 }\n}";
+        let error = expand_within(script, 1000).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "2:3: no solution: the script runs more than 1000 steps"
+        );
+        assert_eq!(expand_within("repeat 3 { level }", 6).unwrap().len(), 3);
+        assert!(expand_within("repeat 3 { level }", 5).is_err());
+    }
+}
@@ -0,0 +1,325 @@
+//! Shortest command sequences that take the submarine to a target.
+//!
+//! Sequences use positive amounts for `forward`, `back`, `up` and `down`, and
+//! any amount for `set-aim`, so every command is one a human would write.
+
+use std::fmt::{self, Display};
+
+use aoc_common::{
+    parse::{exact_fields, number},
+    ParseError,
+};
+
+use crate::{Direction, Interpretation};
+
+const MAX: i64 = i32::MAX as i64;
+
+/// Why no sequence was synthesised.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Unreachable {
+    /// The shortest sequence has `needed` commands, more than `limit`.
+    TooFar { needed: usize, limit: usize },
+}
+
+impl Display for Unreachable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Unreachable::TooFar { needed, limit } => write!(
+                f,
+                "the target takes {needed} commands, more than the limit of {limit}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Unreachable {}
+
+/// Parses a target written `<horizontal>,<depth>`.
+pub fn parse_target(s: &str) -> Result<(i64, i64), ParseError> {
+    let [(column, horizontal), (depth_column, depth)] = exact_fields(s, ",")?;
+    Ok((number(horizontal, column)?, number(depth, depth_column)?))
+}
+
+/// A sequence of at most `limit` commands that moves the submarine from the
+/// surface to `(horizontal, depth)`, a shortest one unless the target is
+/// beyond `±i32::MAX` under [`Interpretation::Aim`].
+///
+/// Every target is reachable, the sequence is only built once it is known to
+/// fit the limit.
+pub fn synthesise(
+    (horizontal, depth): (i64, i64),
+    interpretation: Interpretation,
+    limit: usize,
+) -> Result<Vec<(Direction, i32)>, Unreachable> {
+    let legs = match interpretation {
+        Interpretation::Direct => {
+            let mut legs = vec![travel(horizontal)];
+            legs.extend(dive(depth));
+            legs
+        }
+        Interpretation::Aim => aimed(horizontal, depth),
+    };
+    let needed = legs.iter().map(Leg::len).sum();
+    if needed > limit {
+        return Err(Unreachable::TooFar { needed, limit });
+    }
+
+    Ok(legs.iter().flat_map(Leg::commands).collect())
+}
+
+/// Commands that go together in a sequence.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Leg {
+    /// Moves `total` in one direction, `i32::MAX` at a time.
+    Move(Direction, u64),
+    SetAim(i32),
+}
+
+impl Leg {
+    fn len(&self) -> usize {
+        match *self {
+            Leg::Move(_, total) => total.div_ceil(MAX as u64) as usize,
+            Leg::SetAim(_) => 1,
+        }
+    }
+
+    fn commands(&self) -> Box<dyn Iterator<Item = (Direction, i32)>> {
+        match *self {
+            Leg::Move(direction, total) => {
+                Box::new(chunks(total).map(move |amount| (direction, amount)))
+            }
+            Leg::SetAim(aim) => Box::new(std::iter::once((Direction::SetAim, aim))),
+        }
+    }
+}
+
+/// Moves `distance` horizontally.
+fn travel(distance: i64) -> Leg {
+    let direction = if distance < 0 {
+        Direction::Back
+    } else {
+        Direction::Forward
+    };
+    Leg::Move(direction, distance.unsigned_abs())
+}
+
+/// Sets the depth under [`Interpretation::Direct`].
+///
+/// Only the first command can be `set-aim`, which reaches one further down
+/// than `up` can reach up, every later one would undo the others.
+fn dive(depth: i64) -> Vec<Leg> {
+    if depth >= -MAX {
+        let direction = if depth < 0 {
+            Direction::Up
+        } else {
+            Direction::Down
+        };
+        return vec![Leg::Move(direction, depth.unsigned_abs())];
+    }
+    let rest = depth.unsigned_abs() - i32::MIN.unsigned_abs() as u64;
+    vec![Leg::SetAim(i32::MIN), Leg::Move(Direction::Up, rest)]
+}
+
+/// Sets the aim under [`Interpretation::Aim`], with `set-aim` alone if it
+/// fits and topped up with `down` or `up` otherwise.
+fn steer(aim: i64) -> Vec<Leg> {
+    match i32::try_from(aim) {
+        Ok(aim) => vec![Leg::SetAim(aim)],
+        Err(_) if aim > 0 => vec![
+            Leg::SetAim(i32::MAX),
+            Leg::Move(Direction::Down, aim.unsigned_abs() - MAX as u64),
+        ],
+        Err(_) => vec![
+            Leg::SetAim(i32::MIN),
+            Leg::Move(
+                Direction::Up,
+                aim.unsigned_abs() - i32::MIN.unsigned_abs() as u64,
+            ),
+        ],
+    }
+}
+
+/// Splits `total` into as few amounts of at most `i32::MAX` as possible.
+fn chunks(total: u64) -> impl Iterator<Item = i32> {
+    let max = MAX as u64;
+    let full = total / max;
+    let rest = total % max;
+    (0..full)
+        .map(|_| i32::MAX)
+        .chain((rest > 0).then_some(rest as i32))
+}
+
+/// A sequence under [`Interpretation::Aim`], a shortest one if both
+/// coordinates are within `±i32::MAX`.
+///
+/// Horizontal moves at aim 0 do not change the depth, and aimed moves of `h`
+/// in total dive by a multiple of `h`. So travelling suffices for depth 0,
+/// an aim and travelling if `h` divides the depth, and otherwise travelling
+/// at aim 0 to within one of the target, an aim of `±depth` and a final move
+/// of one.
+fn aimed(horizontal: i64, depth: i64) -> Vec<Leg> {
+    if depth == 0 {
+        return vec![travel(horizontal)];
+    }
+    if horizontal != 0 && depth.checked_rem(horizontal) == Some(0) {
+        if let Some(aim) = depth.checked_div(horizontal) {
+            let mut legs = steer(aim);
+            legs.push(travel(horizontal));
+            return legs;
+        }
+    }
+    // Moving back last would need an aim of `-i64::MIN`, and `horizontal`
+    // is never `i64::MIN` then, since it divides the depth.
+    let last = if horizontal < 0 && depth != i64::MIN {
+        -1
+    } else {
+        1
+    };
+    let mut legs = vec![travel(horizontal - last)];
+    legs.extend(steer(depth * last));
+    legs.push(travel(last));
+    legs
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{parse_target, synthesise, Unreachable};
+    use crate::{destination, Direction, Interpretation, State};
+
+    fn reaches(target: (i64, i64), interpretation: Interpretation) -> usize {
+        let commands = synthesise(target, interpretation, usize::MAX).unwrap();
+        let state = destination(&commands, interpretation).unwrap();
+        assert_eq!((state.horizontal, state.depth), target, "{commands:?}");
+        commands.len()
+    }
+
+    #[test]
+    fn direct_targets() {
+        let commands = synthesise((15, 10), Interpretation::Direct, 2).unwrap();
+        assert_eq!(
+            commands,
+            vec![(Direction::Forward, 15), (Direction::Down, 10)]
+        );
+        assert_eq!(reaches((0, 0), Interpretation::Direct), 0);
+        assert_eq!(reaches((-3, -4), Interpretation::Direct), 2);
+
+        let max = i64::from(i32::MAX);
+        assert_eq!(reaches((2 * max + 1, 0), Interpretation::Direct), 3);
+        // `set-aim` reaches 2^31 up, one more than `up`.
+        assert_eq!(reaches((0, -max - 1), Interpretation::Direct), 1);
+        assert_eq!(reaches((0, -2 * max - 1), Interpretation::Direct), 2);
+        assert_eq!(reaches((0, 2 * max + 2), Interpretation::Direct), 3);
+    }
+
+    #[test]
+    fn aimed_targets() {
+        let commands = synthesise((15, 60), Interpretation::Aim, 2).unwrap();
+        assert_eq!(
+            commands,
+            vec![(Direction::SetAim, 4), (Direction::Forward, 15)]
+        );
+        assert_eq!(reaches((15, 61), Interpretation::Aim), 3);
+        assert_eq!(reaches((0, 7), Interpretation::Aim), 3);
+        assert_eq!(reaches((-4, 8), Interpretation::Aim), 2);
+        assert_eq!(reaches((-4, 9), Interpretation::Aim), 3);
+        assert_eq!(reaches((-4, 0), Interpretation::Aim), 1);
+    }
+
+    #[test]
+    fn unreachable_targets() {
+        assert_eq!(
+            synthesise((15, 61), Interpretation::Aim, 2),
+            Err(Unreachable::TooFar {
+                needed: 3,
+                limit: 2
+            })
+        );
+        let max = i64::from(i32::MAX);
+        assert_eq!(
+            synthesise((i64::MAX, 0), Interpretation::Aim, 10),
+            Err(Unreachable::TooFar {
+                needed: 4294967299,
+                limit: 10
+            })
+        );
+        assert_eq!(
+            synthesise((0, 3 * max), Interpretation::Direct, 2),
+            Err(Unreachable::TooFar {
+                needed: 3,
+                limit: 2
+            })
+        );
+    }
+
+    #[test]
+    fn aimed_targets_beyond_i32() {
+        let max = i64::from(i32::MAX);
+        assert_eq!(reaches((4294967296, 0), Interpretation::Aim), 3);
+        assert_eq!(reaches((2 * max, 6 * max), Interpretation::Aim), 3);
+        assert_eq!(reaches((1, 3 * max), Interpretation::Aim), 4);
+        assert_eq!(reaches((-2, -4 * max - 1), Interpretation::Aim), 7);
+        assert_eq!(reaches((-1, -3 * max - 2), Interpretation::Aim), 5);
+        assert_eq!(
+            synthesise((i64::MAX, i64::MAX), Interpretation::Aim, 10),
+            Err(Unreachable::TooFar {
+                needed: 4294967300,
+                limit: 10
+            })
+        );
+        // The corners of the range are reachable, if not in a few commands.
+        for target in [(-1, i64::MIN), (i64::MIN, i64::MIN), (i64::MIN, 1)] {
+            let result = synthesise(target, Interpretation::Aim, 10);
+            assert!(matches!(result, Err(Unreachable::TooFar { .. })));
+        }
+    }
+
+    #[test]
+    fn parse_targets() {
+        assert_eq!(parse_target("15,-60"), Ok((15, -60)));
+        assert_eq!(parse_target("15,x").unwrap_err().column(), 4);
+        assert!(parse_target("15").is_err());
+    }
+
+    /// No target reachable with up to two commands of small amounts needs more
+    /// from the synthesiser.
+    #[test]
+    fn aimed_targets_are_shortest() {
+        let mut commands = vec![(Direction::Level, 0)];
+        for amount in 1..=4 {
+            for direction in [
+                Direction::Forward,
+                Direction::Back,
+                Direction::Up,
+                Direction::Down,
+            ] {
+                commands.push((direction, amount));
+            }
+        }
+        commands.extend((-4..=4).map(|amount| (Direction::SetAim, amount)));
+
+        let mut shortest: HashMap<(i64, i64), usize> = HashMap::new();
+        let mut frontier = vec![(State::default(), 0)];
+        while let Some((state, len)) = frontier.pop() {
+            let target = (state.horizontal, state.depth);
+            let best = shortest.entry(target).or_insert(len);
+            *best = (*best).min(len);
+            if len < 2 {
+                for command in &commands {
+                    let next = state.apply(command, Interpretation::Aim).unwrap();
+                    frontier.push((next, len + 1));
+                }
+            }
+        }
+
+        for (target, len) in shortest {
+            assert!(reaches(target, Interpretation::Aim) <= len, "{target:?}");
+        }
+        for horizontal in -5..=5 {
+            for depth in -5..=5 {
+                assert!(reaches((horizontal, depth), Interpretation::Aim) <= 3);
+            }
+        }
+    }
+}
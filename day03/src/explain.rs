@@ -0,0 +1,130 @@
+//! Step-by-step account of how the life support ratings are found.
+
+use aoc_common::output::render_table;
+
+use crate::{
+    rating::{Criterion, Rating, Step},
+    report::Report,
+};
+
+/// Most remaining lines listed per step, the rest are only counted.
+const SHOWN_LINES: usize = 8;
+
+/// The steps to the oxygen generator and CO2 scrubber ratings.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Explanation {
+    pub oxygen: Vec<Step>,
+    pub co2: Vec<Step>,
+}
+
+impl Explanation {
+    pub fn of(report: &Report) -> Self {
+        Self::with(report, &Rating::oxygen(), &Rating::co2())
+    }
+
+    /// The steps under other rules than the puzzle's.
+    pub fn with(
+        report: &Report,
+        oxygen: &Rating<impl Criterion>,
+        co2: &Rating<impl Criterion>,
+    ) -> Self {
+        Self {
+            oxygen: oxygen.explain(report),
+            co2: co2.explain(report),
+        }
+    }
+
+    /// Renders one table per rating, with a row per column looked at and the
+    /// rating below it.
+    pub fn render(&self, report: &Report) -> String {
+        [
+            ("Oxygen generator rating", &self.oxygen),
+            ("CO2 scrubber rating", &self.co2),
+        ]
+        .into_iter()
+        .map(|(title, steps)| format!("{title}\n{}", table(report, steps)))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+    }
+}
+
+fn table(report: &Report, steps: &[Step]) -> String {
+    let header = ["Bit", "Ones", "Zeros", "Keep", "Left", "Lines"];
+    let mut rows = vec![header.map(str::to_string)];
+    for step in steps {
+        let left = step.lines.count_ones();
+        let mut lines: Vec<String> = step
+            .lines
+            .iter_ones()
+            .take(SHOWN_LINES)
+            .map(|index| report.line(index).to_string())
+            .collect();
+        if left > SHOWN_LINES {
+            lines.push(format!("and {} more", left - SHOWN_LINES));
+        }
+        rows.push([
+            (step.column + 1).to_string(),
+            step.ones.to_string(),
+            step.zeros.to_string(),
+            step.kept
+                .map_or("-".to_string(), |bit| u8::from(bit).to_string()),
+            left.to_string(),
+            lines.join(" "),
+        ]);
+    }
+
+    let last = steps
+        .last()
+        .map_or_else(|| report.all(), |step| step.lines.clone());
+    let rating = match last.first_one() {
+        Some(index) => {
+            let rating = report.line(index);
+            format!("Rating: {rating} ({})", rating.to_biguint())
+        }
+        None => "Rating: none, the report is empty".to_string(),
+    };
+    format!("{}\n{rating}", render_table(&rows))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Explanation;
+    use crate::report::Report;
+
+    #[test]
+    fn explain_the_puzzle_example() {
+        let content = std::fs::read_to_string("small_input.txt").unwrap();
+        let report = Report::parse(&content).unwrap();
+        let explanation = Explanation::of(&report);
+        assert_eq!(explanation.oxygen.len(), 5);
+        assert_eq!(explanation.co2.len(), 3);
+        assert_eq!((explanation.co2[0].ones, explanation.co2[0].zeros), (7, 5));
+
+        let expected = "\
+Oxygen generator rating
+Bit  Ones  Zeros  Keep  Left  Lines
+1    7     5      1     7     11110 10110 10111 10101 11100 10000 11001
+2    3     4      0     4     10110 10111 10101 10000
+3    3     1      1     3     10110 10111 10101
+4    2     1      1     2     10110 10111
+5    1     1      1     1     10111
+Rating: 10111 (23)
+
+CO2 scrubber rating
+Bit  Ones  Zeros  Keep  Left  Lines
+1    7     5      0     5     00100 01111 00111 00010 01010
+2    2     3      1     2     01111 01010
+3    1     1      0     1     01010
+Rating: 01010 (10)";
+        assert_eq!(explanation.render(&report), expected);
+    }
+
+    #[test]
+    fn long_steps_are_cut_short() {
+        let content = "0\n".repeat(9) + "1\n";
+        let report = Report::parse(&content).unwrap();
+        let rendered = Explanation::of(&report).render(&report);
+        assert!(rendered.contains("1    1     9      0     9     0 0 0 0 0 0 0 0 and 1 more"));
+        assert!(rendered.ends_with("Rating: 1 (1)"));
+    }
+}
@@ -0,0 +1,193 @@
+//! Day 3: Binary Diagnostic.
+//!
+//! Derives power consumption and life support ratings from a report of
+//! binary numbers.
+
+use aoc_common::{Error, Solution};
+use num_bigint::BigUint;
+
+pub mod explain;
+pub mod rating;
+pub mod report;
+
+use rating::{Criterion, Rating};
+use report::{Binary, Report};
+
+/// [`Solution`] for day 3.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Report;
+    type PartOne = BigUint;
+    type PartTwo = BigUint;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(Report::parse(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> aoc_common::Result<Self::PartOne> {
+        solve_1(input).ok_or_else(|| Error::unsolvable("Empty diagnostic report."))
+    }
+
+    fn part_two(input: &Self::Input) -> aoc_common::Result<Self::PartTwo> {
+        solve_2(input).ok_or_else(|| Error::unsolvable("Empty diagnostic report."))
+    }
+}
+
+/// The gamma rate, the most common bit in every column, and the epsilon rate,
+/// the least common one.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PowerConsumption {
+    pub gamma: Binary,
+    pub epsilon: Binary,
+}
+
+impl PowerConsumption {
+    /// Columns where both bits are equally common count as zeros in the
+    /// gamma rate.
+    ///
+    /// Returns `None` for an empty report.
+    pub fn of(report: &Report) -> Option<Self> {
+        if report.is_empty() {
+            return None;
+        }
+        let all = report.all();
+        let gamma: Binary = (0..report.width())
+            .map(|column| report.most_common(&all, column) == Some(true))
+            .collect();
+        let epsilon = gamma.complement();
+        Some(Self { gamma, epsilon })
+    }
+
+    pub fn product(&self) -> BigUint {
+        self.gamma.to_biguint() * self.epsilon.to_biguint()
+    }
+}
+
+/// The oxygen generator and CO2 scrubber ratings.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LifeSupport {
+    pub oxygen: Binary,
+    pub co2: Binary,
+}
+
+impl LifeSupport {
+    /// Returns `None` for an empty report.
+    pub fn of(report: &Report) -> Option<Self> {
+        Self::with(report, &Rating::oxygen(), &Rating::co2())
+    }
+
+    /// The ratings under other rules than the puzzle's.
+    ///
+    /// Returns `None` for an empty report.
+    pub fn with(
+        report: &Report,
+        oxygen: &Rating<impl Criterion>,
+        co2: &Rating<impl Criterion>,
+    ) -> Option<Self> {
+        Some(Self {
+            oxygen: oxygen.find(report)?,
+            co2: co2.find(report)?,
+        })
+    }
+
+    pub fn product(&self) -> BigUint {
+        self.oxygen.to_biguint() * self.co2.to_biguint()
+    }
+}
+
+/// Power consumption, the product of the gamma and epsilon rates.
+///
+/// Returns `None` for an empty report.
+pub fn solve_1(report: &Report) -> Option<BigUint> {
+    PowerConsumption::of(report).map(|power| power.product())
+}
+
+/// Life support rating, the product of the oxygen generator and CO2 scrubber
+/// ratings.
+///
+/// Returns `None` for an empty report.
+pub fn solve_2(report: &Report) -> Option<BigUint> {
+    LifeSupport::of(report).map(|life_support| life_support.product())
+}
+
+#[cfg(test)]
+mod tests {
+    use bitvec::{bitvec, order::Lsb0};
+
+    use num_bigint::BigUint;
+
+    use super::{solve_1, solve_2, LifeSupport, PowerConsumption, Report};
+    use std::fs;
+
+    #[test]
+    fn bitvec_works() {
+        let actual = bitvec![
+            0, 0, 1, 0, 0, 1, 1, 1, 1, 0, 1, 0, 1, 1, 0, 1, 0, 1, 1, 1, 1, 0, 1, 0, 1, 0, 1, 1, 1,
+            1, 0, 0, 1, 1, 1, 1, 1, 1, 0, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0,
+            1, 0
+        ];
+        let expected = "[001001111010110101111010101111001111110010000110010001001010]";
+        assert_eq!(format!("{:b}", actual), expected);
+    }
+
+    #[test]
+    fn solve_1_works() {
+        let content = fs::read_to_string("small_input.txt").unwrap();
+        let actual = solve_1(&Report::parse(&content).unwrap()).unwrap();
+        let expected = BigUint::from(198u32);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn solve_2_works() {
+        let content = fs::read_to_string("small_input.txt").unwrap();
+        let actual = solve_2(&Report::parse(&content).unwrap()).unwrap();
+        let expected = BigUint::from(230u32);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn majorities_in_reports_of_odd_height() {
+        // Two ones out of three lines make one the most common bit.
+        let report = Report::parse("10\n10\n01").unwrap();
+        let power = PowerConsumption::of(&report).unwrap();
+        assert_eq!(power.gamma.to_string(), "10");
+        assert_eq!(power.epsilon.to_string(), "01");
+        assert_eq!(solve_1(&report), Some(BigUint::from(2u8)));
+    }
+
+    #[test]
+    fn reports_wider_than_machine_words() {
+        let zeros = "0".repeat(98);
+        let content = format!("1{zeros}0\n1{zeros}1\n0{}\n", "1".repeat(99));
+        let report = Report::parse(&content).unwrap();
+        let one = BigUint::from(1u8);
+        let power_of_two = &one << 99u32;
+
+        let power = PowerConsumption::of(&report).unwrap();
+        assert_eq!(power.gamma.to_string(), format!("1{zeros}1"));
+        assert_eq!(power.epsilon.to_string(), format!("0{}0", "1".repeat(98)));
+        let expected = (&power_of_two + &one) * (&power_of_two - BigUint::from(2u8));
+        assert_eq!(solve_1(&report), Some(expected));
+
+        let life_support = LifeSupport::of(&report).unwrap();
+        assert_eq!(life_support.oxygen.to_string(), format!("1{zeros}1"));
+        assert_eq!(life_support.co2.to_string(), format!("0{}", "1".repeat(99)));
+        let expected = (&power_of_two + &one) * (&power_of_two - &one);
+        assert_eq!(solve_2(&report), Some(expected));
+    }
+
+    #[test]
+    fn duplicate_lines_still_have_a_rating() {
+        let report = Report::parse("110\n110\n001\n").unwrap();
+        let life_support = LifeSupport::of(&report).unwrap();
+        assert_eq!(life_support.oxygen.to_string(), "110");
+        assert_eq!(life_support.co2.to_string(), "001");
+
+        let report = Report::parse("101\n101\n").unwrap();
+        assert_eq!(LifeSupport::of(&report).unwrap().co2.to_string(), "101");
+    }
+}
@@ -0,0 +1,221 @@
+//! Ratings found by narrowing a report down one column at a time.
+//!
+//! The oxygen generator and CO2 scrubber ratings of the puzzle are the two
+//! built-in [`Rating`]s, other diagnostic formats plug in their own
+//! [`Criterion`], [`Tie`] policy and scan [`Order`].
+
+use crate::report::{Binary, Lines, Report};
+
+/// Picks the bit to keep in a column.
+pub trait Criterion {
+    /// The bit to keep given how many of the remaining lines have a one and a
+    /// zero in the column, or `None` to leave it to the [`Tie`] policy.
+    fn pick(&self, ones: usize, zeros: usize) -> Option<bool>;
+}
+
+/// Keeps the most common bit, a tie if both are equally common.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct MostCommon;
+
+impl Criterion for MostCommon {
+    fn pick(&self, ones: usize, zeros: usize) -> Option<bool> {
+        (ones != zeros).then_some(ones > zeros)
+    }
+}
+
+/// Keeps the least common bit, a tie if both are equally common.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct LeastCommon;
+
+impl Criterion for LeastCommon {
+    fn pick(&self, ones: usize, zeros: usize) -> Option<bool> {
+        (ones != zeros).then_some(ones < zeros)
+    }
+}
+
+/// Any function of the counts of ones and zeros is a criterion.
+impl<F: Fn(usize, usize) -> Option<bool>> Criterion for F {
+    fn pick(&self, ones: usize, zeros: usize) -> Option<bool> {
+        self(ones, zeros)
+    }
+}
+
+/// What to keep when the criterion does not pick a bit.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Tie {
+    /// Keep the lines with a one.
+    Ones,
+    /// Keep the lines with a zero.
+    Zeros,
+    /// Keep all lines and move on to the next column.
+    Skip,
+}
+
+/// The order in which columns are looked at.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Order {
+    /// From the leftmost, most significant bit.
+    #[default]
+    MsbFirst,
+    /// From the rightmost, least significant bit.
+    LsbFirst,
+}
+
+/// A rule that narrows a report down to a single line.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Rating<C> {
+    pub criterion: C,
+    pub tie: Tie,
+    pub order: Order,
+}
+
+impl Rating<MostCommon> {
+    /// The most common bit, ones on a tie.
+    pub fn oxygen() -> Self {
+        Self::new(MostCommon, Tie::Ones)
+    }
+}
+
+impl Rating<LeastCommon> {
+    /// The least common bit, zeros on a tie.
+    pub fn co2() -> Self {
+        Self::new(LeastCommon, Tie::Zeros)
+    }
+}
+
+impl<C: Criterion> Rating<C> {
+    /// Scans from the most significant bit.
+    pub fn new(criterion: C, tie: Tie) -> Self {
+        Self {
+            criterion,
+            tie,
+            order: Order::MsbFirst,
+        }
+    }
+
+    /// The bit to keep in a column with `ones` ones and `zeros` zeros among the
+    /// remaining lines, `None` to keep all of them.
+    pub fn pick(&self, ones: usize, zeros: usize) -> Option<bool> {
+        self.criterion.pick(ones, zeros).or(match self.tie {
+            Tie::Ones => Some(true),
+            Tie::Zeros => Some(false),
+            Tie::Skip => None,
+        })
+    }
+
+    /// Narrows `report` down until one line is left or all columns are used,
+    /// returning the first remaining line.
+    ///
+    /// A column is skipped if no remaining line has the picked bit, which
+    /// happens when duplicate lines agree on it and the least common bit is
+    /// picked.
+    ///
+    /// Returns `None` for an empty report.
+    pub fn find(&self, report: &Report) -> Option<Binary> {
+        self.narrow(report, None)
+            .first_one()
+            .map(|index| report.line(index))
+    }
+
+    /// The columns [`Rating::find`] looks at, in order, with the lines left
+    /// after each.
+    pub fn explain(&self, report: &Report) -> Vec<Step> {
+        let mut steps = Vec::new();
+        self.narrow(report, Some(&mut steps));
+        steps
+    }
+
+    /// The lines left after narrowing `report` down, recording every column
+    /// in `steps`.
+    fn narrow(&self, report: &Report, mut steps: Option<&mut Vec<Step>>) -> Lines {
+        let mut lines = report.all();
+        let columns: Box<dyn Iterator<Item = usize>> = match self.order {
+            Order::MsbFirst => Box::new(0..report.width()),
+            Order::LsbFirst => Box::new((0..report.width()).rev()),
+        };
+        for column in columns {
+            let remaining = lines.count_ones();
+            if remaining <= 1 {
+                break;
+            }
+            let ones = report.ones(&lines, column).count_ones();
+            let zeros = remaining - ones;
+            let kept = match self.pick(ones, zeros) {
+                Some(true) if ones > 0 => Some(true),
+                Some(false) if zeros > 0 => Some(false),
+                _ => None,
+            };
+            if let Some(bit) = kept {
+                report.keep(&mut lines, column, bit);
+            }
+            if let Some(steps) = steps.as_deref_mut() {
+                steps.push(Step {
+                    column,
+                    ones,
+                    zeros,
+                    kept,
+                    lines: lines.clone(),
+                });
+            }
+        }
+
+        lines
+    }
+}
+
+/// One column looked at while finding a rating.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Step {
+    /// The column, 0 being the leftmost.
+    pub column: usize,
+    /// Lines with a one in the column before narrowing down.
+    pub ones: usize,
+    /// Lines with a zero in the column before narrowing down.
+    pub zeros: usize,
+    /// The bit of the lines that were kept, `None` if the column was skipped.
+    pub kept: Option<bool>,
+    /// The lines left after the column.
+    pub lines: Lines,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Order, Rating, Tie};
+    use crate::report::Report;
+
+    const SMALL: &str =
+        "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010";
+
+    #[test]
+    fn puzzle_ratings() {
+        let report = Report::parse(SMALL).unwrap();
+        assert_eq!(Rating::oxygen().find(&report).unwrap().to_string(), "10111");
+        assert_eq!(Rating::co2().find(&report).unwrap().to_string(), "01010");
+    }
+
+    #[test]
+    fn tie_policies_and_scan_order() {
+        let report = Report::parse("10\n01\n").unwrap();
+        let rating = |tie, order| {
+            let rating = Rating {
+                tie,
+                order,
+                ..Rating::oxygen()
+            };
+            rating.find(&report).unwrap().to_string()
+        };
+        assert_eq!(rating(Tie::Ones, Order::MsbFirst), "10");
+        assert_eq!(rating(Tie::Zeros, Order::MsbFirst), "01");
+        assert_eq!(rating(Tie::Ones, Order::LsbFirst), "01");
+        assert_eq!(rating(Tie::Skip, Order::LsbFirst), "10");
+    }
+
+    #[test]
+    fn custom_criteria() {
+        let report = Report::parse(SMALL).unwrap();
+        // Keep ones unless fewer than three lines have them.
+        let rating = Rating::new(|ones: usize, _zeros: usize| Some(ones >= 3), Tie::Skip);
+        assert_eq!(rating.find(&report).unwrap().to_string(), "11001");
+        assert!(rating.find(&Report::default()).is_none());
+    }
+}
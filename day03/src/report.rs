@@ -0,0 +1,277 @@
+//! Diagnostic reports stored column by column as bitsets.
+//!
+//! Bit `i` of column `c` is the `c`-th character of line `i`, so counting
+//! the ones in a column and narrowing down a set of lines by one bit are both
+//! a pass over machine words instead of over characters.
+
+use std::fmt::{self, Display};
+
+use aoc_common::ParseError;
+use bitvec::prelude::*;
+use num_bigint::BigUint;
+
+const BITS: usize = usize::BITS as usize;
+
+/// Lines of a report, one bit per line.
+pub type Lines = BitVec;
+
+/// A diagnostic report of equally wide binary numbers.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Report {
+    width: usize,
+    len: usize,
+    columns: Vec<Lines>,
+}
+
+impl Report {
+    /// Reads one binary number per line, all as wide as the first line.
+    ///
+    /// Fails on an empty report, on characters other than `0` and `1` and on
+    /// lines that are shorter or longer than the first.
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let width = match s.lines().next() {
+            None => return Err(ParseError::unexpected_end("a diagnostic report", 1)),
+            Some("") => return Err(ParseError::unexpected_end("a binary number", 1)),
+            Some(first) => first.len(),
+        };
+        let mut words = vec![Vec::new(); width];
+        // The word being filled in every column, flushed every `BITS` lines.
+        let mut current = vec![0usize; width];
+        let mut len = 0;
+        for line in s.lines() {
+            if line.len() != width || line.bytes().any(|b| b != b'0' && b != b'1') {
+                return Err(invalid(line, width).offset(len, 0));
+            }
+            let bit = len % BITS;
+            for (word, &b) in current.iter_mut().zip(line.as_bytes()) {
+                *word |= usize::from(b == b'1') << bit;
+            }
+            len += 1;
+            if len % BITS == 0 {
+                for (column, word) in words.iter_mut().zip(&mut current) {
+                    column.push(std::mem::take(word));
+                }
+            }
+        }
+        if len % BITS != 0 {
+            for (column, word) in words.iter_mut().zip(current) {
+                column.push(word);
+            }
+        }
+
+        let columns = words
+            .into_iter()
+            .map(|words| {
+                let mut column = Lines::from_vec(words);
+                column.truncate(len);
+                column
+            })
+            .collect();
+        Ok(Self {
+            width,
+            len,
+            columns,
+        })
+    }
+
+    /// Number of bits per line.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of lines.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The bits of `column` in every line, column 0 being the leftmost.
+    pub fn column(&self, column: usize) -> &BitSlice {
+        &self.columns[column]
+    }
+
+    /// Every line of the report.
+    pub fn all(&self) -> Lines {
+        bitvec![1; self.len]
+    }
+
+    /// The lines among `lines` that have a one in `column`.
+    ///
+    /// Works a word at a time, which bitvec's operators on slices do not.
+    pub fn ones(&self, lines: &Lines, column: usize) -> Lines {
+        let mut ones = lines.clone();
+        ones.as_raw_mut_slice()
+            .iter_mut()
+            .zip(self.columns[column].as_raw_slice())
+            .for_each(|(line, bit)| *line &= bit);
+        ones
+    }
+
+    /// The most common bit in `column` among `lines`, or `None` if both are
+    /// equally common.
+    pub fn most_common(&self, lines: &Lines, column: usize) -> Option<bool> {
+        let ones = self.ones(lines, column).count_ones();
+        let zeros = lines.count_ones() - ones;
+        (ones != zeros).then_some(ones > zeros)
+    }
+
+    /// The least common bit in `column` among `lines`, or `None` if both are
+    /// equally common.
+    pub fn least_common(&self, lines: &Lines, column: usize) -> Option<bool> {
+        self.most_common(lines, column).map(|bit| !bit)
+    }
+
+    /// Keeps the lines that have `bit` in `column`.
+    pub fn keep(&self, lines: &mut Lines, column: usize, bit: bool) {
+        let ones = self.ones(lines, column);
+        if bit {
+            *lines = ones;
+        } else {
+            lines
+                .as_raw_mut_slice()
+                .iter_mut()
+                .zip(ones.as_raw_slice())
+                .for_each(|(line, one)| *line ^= one);
+        }
+    }
+
+    /// The number on line `index`.
+    pub fn line(&self, index: usize) -> Binary {
+        self.columns.iter().map(|column| column[index]).collect()
+    }
+}
+
+/// The error for a `line` that is not a binary number of `width` bits.
+fn invalid(line: &str, width: usize) -> ParseError {
+    let mut chars = line.chars().enumerate();
+    match chars.find(|&(idx, c)| idx == width || (c != '0' && c != '1')) {
+        Some((idx, c)) if idx == width => {
+            ParseError::unexpected_token(c, "end of line, like the first line", idx + 1)
+        }
+        Some((idx, c)) => ParseError::unexpected_token(c, "`0` or `1`", idx + 1),
+        None => ParseError::unexpected_end("as many bits as the first line", line.len() + 1),
+    }
+}
+
+/// A binary number of any width, leftmost bit first.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Binary(BitVec);
+
+impl Binary {
+    pub fn bits(&self) -> &BitSlice {
+        &self.0
+    }
+
+    /// The number with every bit flipped, as wide as this one.
+    pub fn complement(&self) -> Self {
+        Self(!self.0.clone())
+    }
+
+    pub fn to_biguint(&self) -> BigUint {
+        let digits: Vec<u8> = self.0.iter().map(|bit| u8::from(*bit)).collect();
+        BigUint::from_radix_be(&digits, 2).expect("bits are binary digits")
+    }
+}
+
+impl FromIterator<bool> for Binary {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// The bit string, with leading zeros.
+impl Display for Binary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0
+            .iter()
+            .try_for_each(|bit| f.write_str(if *bit { "1" } else { "0" }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use super::{Binary, Report};
+
+    #[test]
+    fn columns_hold_the_bits_of_every_line() {
+        let report = Report::parse("010\n010\n101\n011\n").unwrap();
+        assert_eq!((report.width(), report.len()), (3, 4));
+        assert_eq!(report.column(2).iter_ones().collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(report.line(2).to_string(), "101");
+
+        let mut lines = report.all();
+        report.keep(&mut lines, 0, false);
+        assert_eq!(lines.iter_ones().collect::<Vec<_>>(), vec![0, 1, 3]);
+        report.keep(&mut lines, 2, true);
+        assert_eq!(lines.iter_ones().collect::<Vec<_>>(), vec![3]);
+    }
+
+    #[test]
+    fn parse_rejects_malformed_reports() {
+        let error = |s| {
+            let error = Report::parse(s).unwrap_err();
+            (error.line(), error.column(), error.to_string())
+        };
+        assert_eq!(
+            error("0101\n0121\n"),
+            (2, 3, "2:3: expected `0` or `1`, found `2`".to_string())
+        );
+        assert_eq!(
+            error("0101\n011\n"),
+            (
+                2,
+                4,
+                "2:4: expected as many bits as the first line, found end of input".to_string()
+            )
+        );
+        assert_eq!(error("0101\n01010\n").1, 5);
+        assert_eq!(error("0101\n\n0101").0, 2);
+        assert_eq!(error("01\n0é").1, 2);
+        assert_eq!(
+            error(""),
+            (
+                1,
+                1,
+                "1:1: expected a diagnostic report, found end of input".to_string()
+            )
+        );
+        assert_eq!(
+            error("\n").2,
+            "1:1: expected a binary number, found end of input"
+        );
+    }
+
+    #[test]
+    fn binary_numbers_have_any_width() {
+        let bits = "1".repeat(100) + "0";
+        let binary: Binary = bits.bytes().map(|b| b == b'1').collect();
+        assert_eq!(binary.to_string(), bits);
+        assert_eq!(binary.complement().to_string(), "0".repeat(100) + "1");
+        let expected = (BigUint::from(1u8) << 101u32) - BigUint::from(2u8);
+        assert_eq!(binary.to_biguint(), expected);
+        assert_eq!(Binary::default().to_biguint(), BigUint::default());
+    }
+
+    #[test]
+    fn most_common_works() {
+        let report = Report::parse("010\n010\n101\n011\n").unwrap();
+        let all = report.all();
+        assert_eq!(report.most_common(&all, 0), Some(false));
+        assert_eq!(report.most_common(&all, 1), Some(true));
+        assert_eq!(report.most_common(&all, 2), None);
+    }
+
+    #[test]
+    fn least_common_works() {
+        let report = Report::parse("010\n010\n101\n011\n").unwrap();
+        let all = report.all();
+        assert_eq!(report.least_common(&all, 0), Some(true));
+        assert_eq!(report.least_common(&all, 1), Some(false));
+        assert_eq!(report.least_common(&all, 2), None);
+    }
+}
@@ -0,0 +1,391 @@
+//! Day 4: Giant Squid.
+//!
+//! Plays bingo against a giant squid.
+
+use aoc_common::{
+    parse::{exact_words, fields, number},
+    ParseError, Solution,
+};
+use std::{collections::VecDeque, str::FromStr};
+
+/// [`Solution`] for day 4.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Input;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(Input::from_str(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> aoc_common::Result<Self::PartOne> {
+        Ok(solve_1(&mut input.clone()))
+    }
+
+    fn part_two(input: &Self::Input) -> aoc_common::Result<Self::PartTwo> {
+        Ok(solve_2(&mut input.clone()))
+    }
+}
+
+/// Score of the first board to win. Consumes the called numbers from `input`.
+pub fn solve_1(input: &mut Input) -> u32 {
+    for _ in 0..4 {
+        if let Some(n) = input.numbers.pop_front() {
+            for board in &mut input.boards {
+                board.call_number(n);
+            }
+            // We don't check for bingo here, because we need at least 5 calls for a bingo.
+        }
+    }
+
+    let mut number = 0;
+
+    let winning_board_sum: u32 = loop {
+        if let Some(n) = input.numbers.pop_front() {
+            number = n;
+            for board in &mut input.boards {
+                board.call_number(number);
+            }
+
+            if let Some(winner) = input.boards.iter().find(|&board| board.has_bingo()) {
+                break winner.remaining_sum();
+            }
+        } else {
+            break 0; // No more numbers to call.
+        }
+    };
+
+    winning_board_sum * number
+}
+
+/// Score of the last board to win. Consumes the called numbers from `input`.
+pub fn solve_2(input: &mut Input) -> u32 {
+    input.boards.retain(|board| !board.has_bingo()); // First, remove boards that already won.
+
+    let mut number = 0;
+
+    let last_winning_board_sum = loop {
+        if let Some(n) = input.numbers.pop_front() {
+            number = n;
+
+            for board in &mut input.boards {
+                board.call_number(number);
+            }
+
+            match input.boards.len() {
+                0 => break 0, // We had multiple boards that won simultaneously.
+                1 => {
+                    if input.boards[0].has_bingo() {
+                        break input.boards[0].remaining_sum(); // We have a last winner.
+                    }
+                }
+                _ => input.boards.retain(|board| !board.has_bingo()), // Remove boards that won.
+            }
+        } else {
+            break 0;
+        }
+    };
+
+    last_winning_board_sum * number
+}
+
+/// A 5x5 bingo board.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Board {
+    /// Numbers per row that have not been called yet.
+    pub rows: [Vec<u32>; 5],
+    /// Numbers per column that have not been called yet.
+    pub cols: [Vec<u32>; 5],
+}
+
+impl Board {
+    /// Marks `number` on the board.
+    pub fn call_number(&mut self, number: u32) {
+        for row in &mut self.rows {
+            row.retain(|n| *n != number);
+        }
+        for col in &mut self.cols {
+            col.retain(|n| *n != number);
+        }
+    }
+
+    /// Whether a whole row or column has been marked.
+    pub fn has_bingo(&self) -> bool {
+        self.rows.iter().any(Vec::is_empty) || self.cols.iter().any(Vec::is_empty)
+    }
+
+    /// Sum of all unmarked numbers.
+    pub fn remaining_sum(&self) -> u32 {
+        self.rows.iter().flatten().sum()
+    }
+}
+
+impl FromStr for Board {
+    type Err = ParseError;
+
+    /// Parses five lines of five whitespace separated numbers each.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rows: [Vec<u32>; 5] = [Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new()];
+        let mut cols: [Vec<u32>; 5] = [Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new()];
+        let mut lines = s.lines().enumerate();
+        for (row_count, row) in rows.iter_mut().enumerate() {
+            let (_, line) = lines
+                .next()
+                .ok_or_else(|| ParseError::unexpected_end("a board row", 1).offset(row_count, 0))?;
+            let words = exact_words::<5>(line).map_err(|e| e.offset(row_count, 0))?;
+            for (column_count, (column, number_string)) in words.into_iter().enumerate() {
+                let number = number(number_string, column).map_err(|e| e.offset(row_count, 0))?;
+                row.push(number);
+                cols[column_count].push(number);
+            }
+        }
+        if let Some((idx, line)) = lines.next() {
+            return Err(ParseError::unexpected_token(line, "an empty line", 1).offset(idx, 0));
+        }
+
+        Ok(Board { rows, cols })
+    }
+}
+
+/// The numbers to call and the boards to play on.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Input {
+    pub numbers: VecDeque<u32>,
+    pub boards: Vec<Board>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s.lines().collect();
+        let first = lines
+            .first()
+            .ok_or_else(|| ParseError::unexpected_end("the numbers to call", 1))?;
+        let numbers = fields(first, ",")
+            .map(|(column, ns)| number(ns, column))
+            .collect::<Result<VecDeque<u32>, _>>()?;
+
+        // Boards are blocks of lines separated by empty lines, `start` is the
+        // index of the first line of the current block.
+        let mut boards = Vec::new();
+        let mut start = 1;
+        for block in lines[1..].split(|line| line.is_empty()) {
+            if !block.is_empty() {
+                let board = Board::from_str(&block.join("\n")).map_err(|e| e.offset(start, 0))?;
+                boards.push(board);
+            }
+            start += block.len() + 1;
+        }
+        if boards.is_empty() {
+            return Err(ParseError::unexpected_end("a board", 1).offset(lines.len(), 0));
+        }
+
+        Ok(Self { numbers, boards })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::VecDeque, fs, str::FromStr, vec};
+
+    use aoc_common::ParseError;
+
+    use super::{solve_1, solve_2, Board, Input};
+
+    #[test]
+    fn solve_2_works() {
+        let mut input =
+            Input::from_str(fs::read_to_string("small_input.txt").unwrap().as_str()).unwrap();
+        let expected = 1924;
+        let actual = solve_2(&mut input);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn solve_1_works() {
+        let mut input =
+            Input::from_str(fs::read_to_string("small_input.txt").unwrap().as_ref()).unwrap();
+        let expected = 4512;
+        let actual = solve_1(&mut input);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn remaining_sum_works() {
+        let board = Board {
+            rows: [
+                vec![22, 13, 17, 11, 0],
+                vec![8, 2, 23, 4, 24],
+                vec![21, 9, 14, 16, 7],
+                vec![6, 10, 3, 18, 5],
+                vec![1, 12, 20, 15, 19],
+            ],
+            cols: [
+                vec![22, 8, 21, 6, 1],
+                vec![13, 2, 9, 10, 12],
+                vec![17, 23, 14, 3, 20],
+                vec![11, 4, 16, 18, 15],
+                vec![0, 24, 7, 5, 19],
+            ],
+        };
+
+        let expected = 300;
+        let actual = board.remaining_sum();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn has_bingo_false_when_no_empty_vecs() {
+        let board = Board {
+            rows: [vec![1], vec![1], vec![1], vec![1], vec![1]],
+            cols: [vec![2], vec![2], vec![2], vec![2], vec![2]],
+        };
+
+        assert!(!board.has_bingo());
+    }
+
+    #[test]
+    fn has_bingo_true_when_at_least_one_empty_vec() {
+        let board = Board {
+            rows: [vec![1], vec![1], vec![1], vec![1], vec![1]],
+            cols: [vec![], vec![2], vec![2], vec![2], vec![2]],
+        };
+
+        assert!(board.has_bingo());
+    }
+
+    #[test]
+    fn call_number_removes_number_from_all_vecs() {
+        let mut board = Board {
+            rows: [vec![1], vec![1], vec![1], vec![1], vec![1]],
+            cols: [vec![2], vec![2], vec![2], vec![2], vec![2]],
+        };
+
+        board.call_number(1);
+        assert!(board.rows.iter().all(Vec::is_empty));
+        assert!(!board.cols.iter().all(Vec::is_empty));
+
+        board.call_number(2);
+        assert!(board.cols.iter().all(Vec::is_empty));
+    }
+
+    #[test]
+    fn call_number_no_change_when_number_is_not_on_board() {
+        let mut board = Board {
+            rows: [vec![1], vec![1], vec![1], vec![1], vec![1]],
+            cols: [vec![2], vec![2], vec![2], vec![2], vec![2]],
+        };
+
+        board.call_number(3);
+
+        assert!(!board.rows.iter().all(Vec::is_empty));
+        assert!(!board.cols.iter().all(Vec::is_empty));
+    }
+
+    #[test]
+    fn from_str_board_works() {
+        let input =
+            "22 13 17 11  0\n 8  2 23  4 24\n21  9 14 16  7\n 6 10  3 18  5\n 1 12 20 15 19";
+        let expected = Board {
+            rows: [
+                vec![22, 13, 17, 11, 0],
+                vec![8, 2, 23, 4, 24],
+                vec![21, 9, 14, 16, 7],
+                vec![6, 10, 3, 18, 5],
+                vec![1, 12, 20, 15, 19],
+            ],
+            cols: [
+                vec![22, 8, 21, 6, 1],
+                vec![13, 2, 9, 10, 12],
+                vec![17, 23, 14, 3, 20],
+                vec![11, 4, 16, 18, 15],
+                vec![0, 24, 7, 5, 19],
+            ],
+        };
+        let actual = Board::from_str(input).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn from_str_input_works() {
+        let input = fs::read_to_string("small_input.txt").unwrap();
+        let expected = Input {
+            numbers: VecDeque::from([
+                7, 4, 9, 5, 11, 17, 23, 2, 0, 14, 21, 24, 10, 16, 13, 6, 15, 25, 12, 22, 18, 20, 8,
+                19, 3, 26, 1,
+            ]),
+            boards: vec![
+                Board {
+                    rows: [
+                        vec![22, 13, 17, 11, 0],
+                        vec![8, 2, 23, 4, 24],
+                        vec![21, 9, 14, 16, 7],
+                        vec![6, 10, 3, 18, 5],
+                        vec![1, 12, 20, 15, 19],
+                    ],
+                    cols: [
+                        vec![22, 8, 21, 6, 1],
+                        vec![13, 2, 9, 10, 12],
+                        vec![17, 23, 14, 3, 20],
+                        vec![11, 4, 16, 18, 15],
+                        vec![0, 24, 7, 5, 19],
+                    ],
+                },
+                Board {
+                    rows: [
+                        vec![3, 15, 0, 2, 22],
+                        vec![9, 18, 13, 17, 5],
+                        vec![19, 8, 7, 25, 23],
+                        vec![20, 11, 10, 24, 4],
+                        vec![14, 21, 16, 12, 6],
+                    ],
+                    cols: [
+                        vec![3, 9, 19, 20, 14],
+                        vec![15, 18, 8, 11, 21],
+                        vec![0, 13, 7, 10, 16],
+                        vec![2, 17, 25, 24, 12],
+                        vec![22, 5, 23, 4, 6],
+                    ],
+                },
+                Board {
+                    rows: [
+                        vec![14, 21, 17, 24, 4],
+                        vec![10, 16, 15, 9, 19],
+                        vec![18, 8, 23, 26, 20],
+                        vec![22, 11, 13, 6, 5],
+                        vec![2, 0, 12, 3, 7],
+                    ],
+                    cols: [
+                        vec![14, 10, 18, 22, 2],
+                        vec![21, 16, 8, 11, 0],
+                        vec![17, 15, 23, 13, 12],
+                        vec![24, 9, 26, 6, 3],
+                        vec![4, 19, 20, 5, 7],
+                    ],
+                },
+            ],
+        };
+
+        let actual = Input::from_str(&input).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn parse_reports_board_position() {
+        let input =
+            "7,4\n\n 1  2  3  4  5\n 6  7  8  9 10\n11 12 13 14\n16 17 18 19 20\n21 22 23 24 25";
+        let expected = ParseError::WrongFieldCount {
+            expected: 5,
+            found: 4,
+            line: 5,
+            column: 12,
+        };
+        assert_eq!(Input::from_str(input), Err(expected));
+    }
+}
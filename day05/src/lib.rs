@@ -0,0 +1,168 @@
+//! Day 5: Hydrothermal Venture.
+//!
+//! Counts the points where lines of hydrothermal vents overlap.
+
+use aoc_common::{
+    parse::{exact_fields, number, parse_lines},
+    ParseError, Solution,
+};
+use std::{collections::HashMap, str::FromStr};
+
+/// [`Solution`] for day 5.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Vec<Vents>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> aoc_common::Result<Self::PartOne> {
+        Ok(solve_part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> aoc_common::Result<Self::PartTwo> {
+        Ok(solve_part_two(input))
+    }
+}
+
+/// Number of points covered by at least two horizontal or vertical vent lines.
+pub fn solve_part_one(vents: &[Vents]) -> usize {
+    vents
+        .iter()
+        .filter(|&vl| vl.start.x == vl.end.x || vl.start.y == vl.end.y)
+        .fold(HashMap::new(), |mut hm: HashMap<Point, u32>, vl| {
+            for point in vl.points() {
+                *(hm.entry(point)).or_default() += 1;
+            }
+
+            hm
+        })
+        .into_iter()
+        .filter(|(_, c)| *c > 1)
+        .count()
+}
+
+/// Number of points covered by at least two vent lines of any direction.
+pub fn solve_part_two(vents: &[Vents]) -> usize {
+    vents
+        .iter()
+        .fold(HashMap::new(), |mut hm: HashMap<Point, u32>, vl| {
+            for point in vl.points() {
+                *(hm.entry(point)).or_default() += 1;
+            }
+
+            hm
+        })
+        .into_iter()
+        .filter(|(_, c)| *c > 1)
+        .count()
+}
+
+/// A point on the ocean floor, parsed from `x,y`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let indent = s.len() - s.trim_start().len();
+        let [(x_column, x), (y_column, y)] =
+            exact_fields(s.trim(), ",").map_err(|e| e.offset(0, indent))?;
+        let x = number(x, x_column).map_err(|e| e.offset(0, indent))?;
+        let y = number(y, y_column).map_err(|e| e.offset(0, indent))?;
+
+        Ok(Self { x, y })
+    }
+}
+
+/// Iterator over every point of a [`Vents`] line, both ends included.
+pub struct VentLine {
+    start: Point,
+    end: Point,
+    current: Option<Point>,
+}
+
+/// A line of vents, parsed from `x1,y1 -> x2,y2`.
+///
+/// Lines are horizontal, vertical or diagonal at exactly 45 degrees.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Vents {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Vents {
+    pub fn new(start: Point, end: Point) -> Self {
+        Vents { start, end }
+    }
+
+    /// Every point covered by this line.
+    pub fn points(&self) -> VentLine {
+        VentLine {
+            start: self.start,
+            end: self.end,
+            current: Some(self.start),
+        }
+    }
+}
+
+impl Iterator for VentLine {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // If self.current is `None` we stop iterating.
+        let current = self.current?;
+
+        let next = Point {
+            x: current.x
+                + match self.start.x.cmp(&self.end.x) {
+                    std::cmp::Ordering::Less => 1,
+                    std::cmp::Ordering::Equal => 0,
+                    std::cmp::Ordering::Greater => -1,
+                },
+            y: current.y
+                + match self.start.y.cmp(&self.end.y) {
+                    std::cmp::Ordering::Less => 1,
+                    std::cmp::Ordering::Equal => 0,
+                    std::cmp::Ordering::Greater => -1,
+                },
+        };
+
+        if current == self.end {
+            self.current = None;
+        } else {
+            self.current = Some(next);
+        }
+
+        Some(current)
+    }
+}
+
+impl FromStr for Vents {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [(start_column, start), (end_column, end)] = exact_fields(s, "->")?;
+        let start = Point::from_str(start).map_err(|e| e.offset(0, start_column - 1))?;
+        let end = Point::from_str(end).map_err(|e| e.offset(0, end_column - 1))?;
+        Ok(Self::new(start, end))
+    }
+}
+
+/// Parses one vent line per line of input.
+pub fn parse_input(s: &str) -> Result<Vec<Vents>, ParseError> {
+    parse_lines(s, Vents::from_str)
+}
+
+#[cfg(test)]
+mod tests;
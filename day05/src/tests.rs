@@ -1,5 +1,7 @@
 use std::{fs, str::FromStr};
 
+use aoc_common::ParseError;
+
 use crate::{parse_input, solve_part_one, solve_part_two, Point, Vents};
 
 #[test]
@@ -53,7 +55,6 @@ fn ventline_impl_iterator_works_diagonal() {
     ];
     let actual: Vec<Point> = Vents::new(Point { x: 8, y: 0 }, Point { x: 0, y: 8 })
         .points()
-        .into_iter()
         .collect();
 
     assert_eq!(actual, expected);
@@ -71,7 +72,6 @@ fn ventline_impl_iterator_works_horizontal() {
     ];
     let actual: Vec<Point> = Vents::new(Point { x: 0, y: 9 }, Point { x: 5, y: 9 })
         .points()
-        .into_iter()
         .collect();
 
     assert_eq!(actual, expected);
@@ -88,7 +88,6 @@ fn ventline_impl_iterator_works_vertical() {
     ];
     let actual: Vec<Point> = Vents::new(Point { x: 4, y: 4 }, Point { x: 4, y: 0 })
         .points()
-        .into_iter()
         .collect();
 
     assert_eq!(actual, expected);
@@ -105,7 +104,7 @@ fn parse_point_success() {
 
 #[test]
 fn parse_point_failure() {
-    for inp in vec!["asdf", "2,", ",4 ", "abc,2", "2,abc "] {
+    for inp in ["asdf", "2,", ",4 ", "abc,2", "2,abc "] {
         assert!(Point::from_str(inp).is_err());
     }
 }
@@ -121,11 +120,34 @@ fn parse_ventline_success() {
 
 #[test]
 fn parse_ventline_failure() {
-    for inp in vec!["0,9 to 5,9", "0,9 -> five,9", "9 -> 5,9", "blub"] {
+    for inp in ["0,9 to 5,9", "0,9 -> five,9", "9 -> 5,9", "blub"] {
         assert!(Vents::from_str(inp).is_err());
     }
 }
 
+#[test]
+fn parse_input_reports_position() {
+    let actual = parse_input("0,9 -> 5,9\n8,0 -> 0,eight");
+    let expected = ParseError::UnexpectedToken {
+        token: "eight".to_string(),
+        expected: "a number",
+        line: 2,
+        column: 10,
+    };
+    assert_eq!(actual, Err(expected));
+
+    let actual = parse_input("0,9 -> 5,9 -> 1,1");
+    assert!(matches!(
+        actual,
+        Err(ParseError::WrongFieldCount {
+            expected: 2,
+            found: 3,
+            line: 1,
+            column: 14
+        })
+    ));
+}
+
 #[test]
 fn parse_small_input_works() {
     let expected = vec![
@@ -0,0 +1,118 @@
+//! Day 6: Lanternfish.
+//!
+//! Simulates an exponentially growing school of lanternfish.
+
+use aoc_common::{
+    parse::{fields, number},
+    ParseError, Solution,
+};
+use std::{collections::HashMap, str::FromStr};
+
+/// [`Solution`] for day 6.
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Lanternfishes;
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(Lanternfishes::from_str(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> aoc_common::Result<Self::PartOne> {
+        Ok(solve_part_1(&mut input.clone()))
+    }
+
+    fn part_two(input: &Self::Input) -> aoc_common::Result<Self::PartTwo> {
+        // `solve_part_2` continues where `solve_part_1` left off.
+        let mut lanternfishes = input.clone();
+        solve_part_1(&mut lanternfishes);
+        Ok(solve_part_2(&mut lanternfishes))
+    }
+}
+
+/// Number of lanternfish after 80 days.
+pub fn solve_part_1(lanternfishes: &mut Lanternfishes) -> <Lanternfishes as Iterator>::Item {
+    lanternfishes.nth(80).unwrap_or_default()
+}
+
+/// Number of lanternfish after 256 days.
+///
+/// Expects `lanternfishes` to have been advanced by [`solve_part_1`] already.
+pub fn solve_part_2(lanternfishes: &mut Lanternfishes) -> <Lanternfishes as Iterator>::Item {
+    lanternfishes.nth(256 - 80 - 1).unwrap_or_default()
+}
+
+/// A school of lanternfish, parsed from a comma separated list of timers.
+///
+/// Iterating yields the size of the school at the start of each day, beginning
+/// with day 0.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Lanternfishes {
+    /// Number of fish per internal timer value.
+    pub fishes: HashMap<u8, u128>,
+}
+
+impl Iterator for Lanternfishes {
+    type Item = u128;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // todo!()
+        // Calculate number of fishes `fish_count`.
+        // Calculate new number of fishes by internal timers (next HashMap).
+        // Return `fish_count`.
+        let fish_count = self.fishes.values().sum();
+
+        self.fishes = self
+            .fishes
+            .iter()
+            .fold(HashMap::new(), |mut hm, (timer, count)| {
+                match timer {
+                    0 => {
+                        // 0 becomes 6...
+                        *(hm.entry(6)).or_default() += count;
+                        // ...and adds an 8.
+                        *(hm.entry(8)).or_default() += count;
+                    }
+                    n => {
+                        // Every other value is decremented.
+                        *(hm.entry(n - 1)).or_default() += count;
+                    }
+                }
+
+                hm
+            });
+
+        Some(fish_count)
+    }
+}
+
+impl FromStr for Lanternfishes {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        let first = lines.next().unwrap_or_default();
+        if let Some(line) = lines.next() {
+            return Err(ParseError::unexpected_token(line, "end of input", 1).offset(1, 0));
+        }
+
+        let fishes = fields(first, ",")
+            .map(|(column, nstr)| number::<u8>(nstr, column))
+            .collect::<Result<Vec<u8>, Self::Err>>()?
+            .into_iter()
+            .fold(HashMap::new(), |mut hm, n| {
+                *(hm.entry(n)).or_default() += 1;
+
+                hm
+            });
+
+        Ok(Self { fishes })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,123 @@
+//! Day 7: The Treachery of Whales.
+//!
+//! Finds the cheapest position for a swarm of crabs to align on.
+
+use aoc_common::Solution;
+
+/// [`Solution`] for day 7.
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Crabs;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(Crabs::from(input.to_string()))
+    }
+
+    fn part_one(input: &Self::Input) -> aoc_common::Result<Self::PartOne> {
+        Ok(solve_part_1(input))
+    }
+
+    fn part_two(input: &Self::Input) -> aoc_common::Result<Self::PartTwo> {
+        Ok(solve_part_2(input))
+    }
+}
+
+/// Fuel needed to align when every step costs one unit of fuel.
+pub fn solve_part_1(crabs: &Crabs) -> u32 {
+    if let Some(m) = crabs.median() {
+        crabs.simple_fuel_cost_for_alignment(m)
+    } else {
+        0
+    }
+}
+
+/// Fuel needed to align when each further step costs one more unit of fuel.
+pub fn solve_part_2(crabs: &Crabs) -> u32 {
+    if let Some(mean) = crabs.mean() {
+        // It's not stupid if it works.
+        (mean - 1..=mean + 1)
+            .map(|m| crabs.additive_fuel_cost_for_alignment(m))
+            .min()
+            .unwrap_or_default()
+    } else {
+        0
+    }
+}
+
+/// Horizontal crab positions, parsed from a comma separated list.
+#[derive(Debug, PartialEq)]
+pub struct Crabs {
+    positions: Vec<u32>,
+}
+
+impl Crabs {
+    /// Positions in ascending order.
+    pub fn positions(&self) -> &[u32] {
+        &self.positions
+    }
+
+    /// Upper median of the positions.
+    pub fn median(&self) -> Option<u32> {
+        let k = (self.positions.len() as f32 / 2.0).ceil() as usize;
+        self.positions.get(k).copied()
+    }
+
+    /// Mean of the positions, rounded up.
+    pub fn mean(&self) -> Option<u32> {
+        let l = self.positions.len();
+        if l == 0 {
+            None
+        } else {
+            let s = self.positions.iter().copied().sum::<u32>() as f64;
+            Some((s / l as f64).ceil() as u32)
+        }
+    }
+
+    /// Fuel needed to align on `alignment` at one unit per step.
+    pub fn simple_fuel_cost_for_alignment(&self, alignment: u32) -> u32 {
+        self.positions
+            .iter()
+            .map(|position| match position.cmp(&alignment) {
+                std::cmp::Ordering::Less => alignment - position,
+                std::cmp::Ordering::Equal => 0,
+                std::cmp::Ordering::Greater => position - alignment,
+            })
+            .sum()
+    }
+
+    /// Fuel needed to align on `alignment` when the n-th step costs n units.
+    pub fn additive_fuel_cost_for_alignment(&self, alignment: u32) -> u32 {
+        self.positions
+            .iter()
+            .map(|position| {
+                let steps = match position.cmp(&alignment) {
+                    std::cmp::Ordering::Less => alignment - position,
+                    std::cmp::Ordering::Equal => 0,
+                    std::cmp::Ordering::Greater => position - alignment,
+                };
+                (steps * (steps + 1)) / 2
+            })
+            .sum()
+    }
+}
+
+impl From<String> for Crabs {
+    fn from(s: String) -> Self {
+        let mut positions: Vec<u32> = s
+            .split(',')
+            .filter_map(|nstr| nstr.parse::<u32>().ok())
+            .collect();
+
+        positions.sort_unstable();
+
+        Self { positions }
+    }
+}
+
+#[cfg(test)]
+mod tests;
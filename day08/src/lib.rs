@@ -0,0 +1,228 @@
+//! Day 8: Seven Segment Search.
+//!
+//! Untangles the scrambled wiring of seven segment displays.
+
+use aoc_common::{
+    parse::{exact_fields, exact_words, parse_lines},
+    Error, ParseError, Solution,
+};
+use itertools::Itertools;
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+/// [`Solution`] for day 8.
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Notes;
+    type PartOne = u32;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(Notes::from_str(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> aoc_common::Result<Self::PartOne> {
+        Ok(solve_1(input))
+    }
+
+    fn part_two(input: &Self::Input) -> aoc_common::Result<Self::PartTwo> {
+        solve_2(input)
+    }
+}
+
+/// Number of output digits that are a 1, 4, 7 or 8.
+pub fn solve_1(notes: &Notes) -> u32 {
+    let sizes = [2, 4, 3, 7];
+    notes
+        .entries
+        .iter()
+        .flat_map(|e| &e.output_digits)
+        .map(|s| s.len())
+        .filter(|l| sizes.contains(l))
+        .count() as u32
+}
+
+/// Sum of all decoded output values.
+pub fn solve_2(notes: &Notes) -> aoc_common::Result<usize> {
+    notes
+        .entries
+        .iter()
+        .enumerate()
+        .map(|(idx, entry)| calculate_output_value(entry).map_err(|e| e.at_line(idx + 1)))
+        .collect::<aoc_common::Result<Vec<_>>>()
+        .map(|v| v.into_iter().sum())
+}
+
+/// Decodes the four digit output value of `entry`.
+pub fn calculate_output_value(entry: &SignalEntry) -> aoc_common::Result<usize> {
+    let wiring: HashMap<&String, u8> = determine_wiring(&entry.signal_patterns)?;
+    Ok(entry
+        .output_digits
+        .iter()
+        .map(|d| {
+            wiring
+                .get(d)
+                .ok_or_else(|| Error::unsolvable(format!("Could not find {} in wiring", d)))
+        })
+        .collect::<aoc_common::Result<Vec<_>>>()?
+        .into_iter()
+        .fold(0, |mut acc, d| {
+            acc *= 10;
+            acc += *d as usize;
+            acc
+        }))
+}
+
+/// Maps each of the ten signal patterns to the digit it displays.
+pub fn determine_wiring(signals: &[String; 10]) -> aoc_common::Result<HashMap<&String, u8>> {
+    let unique_lengths = signals.iter().fold(HashMap::new(), |mut acc, s| {
+        match s.len() {
+            2 => {
+                acc.insert(1, s);
+            }
+            4 => {
+                acc.insert(4, s);
+            }
+            3 => {
+                acc.insert(7, s);
+            }
+            7 => {
+                acc.insert(8, s);
+            }
+            _ => {}
+        }
+        acc
+    });
+
+    let f = |n| {
+        unique_lengths
+            .get(&n)
+            .ok_or_else(|| Error::unsolvable(format!("No pattern for {}", n)))
+    };
+
+    let one = *f(1)?;
+    let four = *f(4)?;
+    let seven = *f(7)?;
+    let eight = *f(8)?;
+
+    let mut wiring = HashMap::from([(one, 1), (four, 4), (seven, 7), (eight, 8)]);
+    for pattern in signals {
+        if wiring.contains_key(&pattern) {
+            continue;
+        }
+
+        match pattern.len() {
+            5 => {
+                wiring.insert(
+                    pattern,
+                    if union_count(pattern, one) == one.len() {
+                        3
+                    } else if union_count(pattern, four) == 3 {
+                        5
+                    } else {
+                        2
+                    },
+                );
+            }
+            6 => {
+                wiring.insert(
+                    pattern,
+                    if union_count(pattern, one) == 1 {
+                        6
+                    } else if union_count(pattern, four) == four.len() {
+                        9
+                    } else {
+                        0
+                    },
+                );
+            }
+            _ => {
+                return Err(Error::unsolvable(format!(
+                    "This one isn't right {}",
+                    pattern
+                )))
+            }
+        }
+    }
+
+    if wiring.len() == 10 {
+        Ok(wiring)
+    } else {
+        Err(Error::unsolvable(
+            "Couldn't put together 10 entries for wiring.",
+        ))
+    }
+}
+
+fn union_count(s1: &String, s2: &String) -> usize {
+    if s1 == s2 {
+        return s1.len();
+    }
+
+    // We assume that the string has no duplicate letters and is sorted.
+    s1.chars()
+        .collect::<HashSet<char>>()
+        .intersection(&s2.chars().collect::<HashSet<char>>())
+        .count()
+}
+
+/// One line of notes, parsed from `<ten patterns> | <four digits>`.
+///
+/// Every pattern and digit has its segments sorted alphabetically.
+#[derive(Debug, PartialEq)]
+pub struct SignalEntry {
+    pub signal_patterns: [String; 10],
+    pub output_digits: [String; 4],
+}
+
+impl FromStr for SignalEntry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [(pattern_column, pattern_str), (output_column, output_str)] = exact_fields(s, "|")?;
+        let signal_patterns =
+            parse_unique_patterns(pattern_str).map_err(|e| e.offset(0, pattern_column - 1))?;
+        let output_digits = parse_output(output_str).map_err(|e| e.offset(0, output_column - 1))?;
+
+        Ok(Self {
+            signal_patterns,
+            output_digits,
+        })
+    }
+}
+
+/// Parses the ten unique signal patterns left of the `|`.
+pub fn parse_unique_patterns(s: &str) -> Result<[String; 10], ParseError> {
+    Ok(exact_words(s)?.map(sorted_word))
+}
+
+/// Parses the four output digits right of the `|`.
+pub fn parse_output(s: &str) -> Result<[String; 4], ParseError> {
+    Ok(exact_words(s)?.map(sorted_word))
+}
+
+fn sorted_word((_, w): (usize, &str)) -> String {
+    w.chars().sorted().collect()
+}
+
+/// All entries of the notes, one per line.
+#[derive(Debug, PartialEq)]
+pub struct Notes {
+    pub entries: Vec<SignalEntry>,
+}
+
+impl FromStr for Notes {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let entries = parse_lines(s, SignalEntry::from_str)?;
+        Ok(Self { entries })
+    }
+}
+
+#[cfg(test)]
+mod tests;
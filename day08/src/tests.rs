@@ -1,6 +1,7 @@
 use crate::{
     calculate_output_value, determine_wiring, solve_1, solve_2, union_count, Notes, SignalEntry,
 };
+use aoc_common::ParseError;
 use std::{collections::HashMap, fs, str::FromStr};
 
 #[test]
@@ -166,3 +167,16 @@ fn parse_signal_entry() {
     let actual = actual_res.unwrap();
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn parse_reports_missing_output_digit() {
+    let s = "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd";
+    let actual = SignalEntry::from_str(s);
+    let expected = ParseError::WrongFieldCount {
+        expected: 4,
+        found: 3,
+        line: 1,
+        column: 82,
+    };
+    assert_eq!(actual, Err(expected));
+}
@@ -0,0 +1,143 @@
+//! Day 9: Smoke Basin.
+//!
+//! Finds the low points and basins of a cave floor height map.
+
+use std::{collections::HashSet, str::FromStr};
+
+use aoc_common::{ParseError, Solution};
+use grid::{Grid, Pos};
+use itertools::Itertools;
+
+/// [`Solution`] for day 9.
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Matrix;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(Matrix::from_str(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> aoc_common::Result<Self::PartOne> {
+        Ok(solve_1(input))
+    }
+
+    fn part_two(input: &Self::Input) -> aoc_common::Result<Self::PartTwo> {
+        Ok(solve_2(input))
+    }
+}
+
+/// Sum of the risk levels of all low points.
+pub fn solve_1(matrix: &Matrix) -> u32 {
+    matrix
+        .low_points()
+        .into_iter()
+        .filter_map(|(x, y)| matrix.get(x, y).map(|n| n as u32 + 1))
+        .sum()
+}
+
+/// Product of the sizes of the three largest basins.
+pub fn solve_2(matrix: &Matrix) -> u32 {
+    matrix
+        .basins()
+        .into_iter()
+        .map(|b| b.len())
+        .sorted_unstable_by(|a, b| Ord::cmp(a, b).reverse())
+        .take(3)
+        .product::<usize>() as u32
+}
+
+/// A height map, parsed from rows of digits.
+#[derive(Debug, PartialEq)]
+pub struct Matrix {
+    heights: Grid<u8>,
+}
+
+impl Matrix {
+    pub fn width(&self) -> usize {
+        self.heights.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.heights.height()
+    }
+
+    /// The basin around every low point.
+    pub fn basins(&self) -> Vec<HashSet<Pos>> {
+        // Calculate the basins for each low point.
+        self.low_points()
+            .into_iter()
+            .map(|lp| self.basin_at(lp))
+            .collect()
+    }
+
+    /// All locations that flow down to `start`, bounded by height 9.
+    pub fn basin_at(&self, start: Pos) -> HashSet<Pos> {
+        let mut visited = HashSet::new();
+        let mut of_interest = vec![start];
+        while let Some(oip) = of_interest.pop() {
+            of_interest.append(
+                &mut self
+                    .heights
+                    .neighbours4(oip)
+                    .filter(|sp @ &(sx, sy)| {
+                        !visited.contains(sp)
+                            && !of_interest.contains(sp)
+                            && self.get(sx, sy).filter(|v| *v != 9).is_some()
+                    })
+                    .collect(),
+            );
+            visited.insert(oip);
+        }
+
+        visited
+    }
+
+    /// Locations that are lower than all of their neighbours.
+    pub fn low_points(&self) -> Vec<Pos> {
+        (0..self.width())
+            .cartesian_product(0..self.height())
+            .filter(|(x, y)| self.is_low_point(*x, *y))
+            .collect()
+    }
+
+    pub fn is_low_point(&self, x: usize, y: usize) -> bool {
+        self.get(x, y)
+            .filter(|n| *n != 9 && self.get_surrounding(x, y).iter().all(|s| *n < *s))
+            .is_some()
+    }
+
+    /// Heights of the horizontal and vertical neighbours of `(x, y)`.
+    pub fn get_surrounding(&self, x: usize, y: usize) -> Vec<u8> {
+        self.heights
+            .neighbours4((x, y))
+            .filter_map(|(nx, ny)| self.get(nx, ny))
+            .collect()
+    }
+
+    /// Height at `(x, y)`, or `None` if it is outside the map.
+    pub fn get(&self, x: usize, y: usize) -> Option<u8> {
+        self.heights.get((x, y)).copied()
+    }
+}
+
+impl From<Grid<u8>> for Matrix {
+    fn from(heights: Grid<u8>) -> Self {
+        Self { heights }
+    }
+}
+
+impl FromStr for Matrix {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(Grid::parse_digits(s)?))
+    }
+}
+
+#[cfg(test)]
+mod tests;
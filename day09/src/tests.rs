@@ -1,10 +1,12 @@
 use std::fs;
 
+use grid::Grid;
+
 use crate::{solve_1, solve_2, Matrix};
 
 #[test]
 fn solve_2_works() {
-    let matrix = Matrix::from(slurp("small_input.txt"));
+    let matrix = slurp("small_input.txt");
     let expected = 1134;
     let actual = solve_2(&matrix);
     assert_eq!(actual, expected);
@@ -12,7 +14,7 @@ fn solve_2_works() {
 
 #[test]
 fn solve_1_works() {
-    let matrix = Matrix::from(slurp("small_input.txt"));
+    let matrix = slurp("small_input.txt");
     let expected = 15;
     let actual = solve_1(&matrix);
     assert_eq!(actual, expected);
@@ -25,7 +27,7 @@ fn get_surrounding_from_matrix() {
     // 9856789892
     // 8767896789
     // 9899965678
-    let matrix = Matrix::from(slurp("small_input.txt"));
+    let matrix = slurp("small_input.txt");
     let expected = vec![
         // left, right, up, down
         (0, 0, vec![1, 3]),
@@ -40,7 +42,7 @@ fn get_surrounding_from_matrix() {
 
 #[test]
 fn get_sample_elements_from_matrix() {
-    let matrix = Matrix::from(slurp("small_input.txt"));
+    let matrix = slurp("small_input.txt");
     let expected = vec![
         (0, 0, Some(2)),
         (0, 1, Some(3)),
@@ -55,19 +57,18 @@ fn get_sample_elements_from_matrix() {
 
 #[test]
 fn parse_matrix_small_input() {
-    let expected = Matrix {
-        data: vec![
+    let expected = Matrix::from(Grid::new(
+        10,
+        5,
+        vec![
             2, 1, 9, 9, 9, 4, 3, 2, 1, 0, 3, 9, 8, 7, 8, 9, 4, 9, 2, 1, 9, 8, 5, 6, 7, 8, 9, 8, 9,
             2, 8, 7, 6, 7, 8, 9, 6, 7, 8, 9, 9, 8, 9, 9, 9, 6, 5, 6, 7, 8,
         ],
-        width: 10,
-        height: 5,
-    };
-    let s = slurp("small_input.txt");
-    let matrix = Matrix::from(s);
+    ));
+    let matrix = slurp("small_input.txt");
     assert_eq!(matrix, expected);
 }
 
-fn slurp(file: &str) -> String {
-    fs::read_to_string(file).unwrap()
+fn slurp(file: &str) -> Matrix {
+    fs::read_to_string(file).unwrap().parse().unwrap()
 }
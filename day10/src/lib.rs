@@ -0,0 +1,190 @@
+//! Day 10: Syntax Scoring.
+//!
+//! Scores corrupted and incomplete lines of brackets.
+
+use aoc_common::{parse::parse_lines, ParseError, Solution};
+use std::{convert::TryFrom, str::FromStr};
+
+/// [`Solution`] for day 10.
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = BracketMatrix;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(BracketMatrix::from_str(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> aoc_common::Result<Self::PartOne> {
+        Ok(solve_1_and_2(input).0)
+    }
+
+    fn part_two(input: &Self::Input) -> aoc_common::Result<Self::PartTwo> {
+        Ok(solve_1_and_2(input).1)
+    }
+}
+
+/// Total syntax error score of the corrupted lines and the median completion
+/// score of the incomplete lines.
+pub fn solve_1_and_2(matrix: &BracketMatrix) -> (u64, u64) {
+    let (part_1, part_2) = matrix
+        .data
+        .iter()
+        .filter_map(BracketLine::check_validity)
+        .fold((0, Vec::new()), |(p1, mut p2), r| match r {
+            Ok(bs) => {
+                p2.push(completion_points(&bs));
+                (p1, p2)
+            }
+            Err(b) => (p1 + syntax_points(&b), p2),
+        });
+
+    (part_1, median(part_2))
+}
+
+/// Median of `numbers`, or 0 if there are none.
+pub fn median(mut numbers: Vec<u64>) -> u64 {
+    let idx = numbers.len() / 2;
+    numbers.sort_unstable();
+    numbers.get(idx).cloned().unwrap_or_default()
+}
+
+/// The four kinds of brackets.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum BType {
+    Round,
+    Square,
+    Curly,
+    Angle,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Bracket {
+    Opening(BType),
+    Closing(BType),
+}
+
+/// Syntax error score of an illegal closing bracket.
+pub fn syntax_points(bracket: &Bracket) -> u64 {
+    match bracket {
+        Bracket::Opening(_) => 0,
+        Bracket::Closing(bt) => match bt {
+            BType::Round => 3,
+            BType::Square => 57,
+            BType::Curly => 1197,
+            BType::Angle => 25137,
+        },
+    }
+}
+
+/// Completion score of the closing brackets that complete a line.
+pub fn completion_points(brackets: &[Bracket]) -> u64 {
+    let mut score = 0;
+    for bracket in brackets {
+        score *= 5;
+        score += match bracket {
+            Bracket::Opening(_) => 0,
+            Bracket::Closing(bt) => match bt {
+                BType::Round => 1,
+                BType::Square => 2,
+                BType::Curly => 3,
+                BType::Angle => 4,
+            },
+        }
+    }
+
+    score
+}
+
+/// Errors point at column 1, the caller knows where the character is.
+impl TryFrom<char> for Bracket {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            '(' => Self::Opening(BType::Round),
+            '[' => Self::Opening(BType::Square),
+            '{' => Self::Opening(BType::Curly),
+            '<' => Self::Opening(BType::Angle),
+            ')' => Self::Closing(BType::Round),
+            ']' => Self::Closing(BType::Square),
+            '}' => Self::Closing(BType::Curly),
+            '>' => Self::Closing(BType::Angle),
+            c => return Err(ParseError::unexpected_token(c, "a bracket", 1)),
+        })
+    }
+}
+
+/// `None` for a complete line, the missing closing brackets for an incomplete
+/// line, or the first illegal bracket for a corrupted line.
+pub type Validity = Option<Result<Vec<Bracket>, Bracket>>;
+
+/// A single line of brackets.
+#[derive(Debug, PartialEq)]
+pub struct BracketLine {
+    pub data: Vec<Bracket>,
+}
+
+impl BracketLine {
+    /// Checks the line for corruption or missing closing brackets.
+    pub fn check_validity(&self) -> Validity {
+        let mut stack = Vec::new();
+        for bracket in self.data.iter() {
+            match bracket {
+                Bracket::Opening(bt) => stack.push(*bt),
+                br @ Bracket::Closing(bt) => {
+                    if let Some(most_recent) = stack.pop() {
+                        if *bt != most_recent {
+                            return Some(Err(*br));
+                        }
+                    } else {
+                        return Some(Err(*br));
+                    }
+                }
+            }
+        }
+
+        if stack.is_empty() {
+            None
+        } else {
+            Some(Ok(stack.into_iter().rev().map(Bracket::Closing).collect()))
+        }
+    }
+}
+
+impl FromStr for BracketLine {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data = s
+            .chars()
+            .enumerate()
+            .map(|(idx, c)| Bracket::try_from(c).map_err(|e| e.offset(0, idx)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { data })
+    }
+}
+
+/// The navigation subsystem, one [`BracketLine`] per line of input.
+#[derive(Debug, PartialEq)]
+pub struct BracketMatrix {
+    pub data: Vec<BracketLine>,
+}
+
+impl FromStr for BracketMatrix {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data = parse_lines(s, BracketLine::from_str)?;
+
+        Ok(Self { data })
+    }
+}
+
+#[cfg(test)]
+mod tests;
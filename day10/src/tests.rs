@@ -1,10 +1,12 @@
 use std::{convert::TryFrom, fs, str::FromStr};
 
+use aoc_common::ParseError;
+
 use crate::{
     solve_1_and_2,
     BType::*,
     Bracket::{self, *},
-    BracketLine, BracketMatrix,
+    BracketLine, BracketMatrix, Validity,
 };
 
 #[test]
@@ -35,7 +37,7 @@ fn solve_1_and_2_works_small_input() {
 
 #[test]
 fn check_validity_works() {
-    let test_data: Vec<(&str, Option<Result<Vec<Bracket>, Bracket>>, &str)> = vec![
+    let test_data: Vec<(&str, Validity, &str)> = vec![
         (
             "{([(<{}[<>[]}>{[]{[(<()>",
             Some(Err(Closing(Curly))),
@@ -416,13 +418,13 @@ fn bracket_matrix_from_str_success() {
         expected_data.len(),
         "Lengths of outer vectors must be equal."
     );
-    for (av, ev) in actual.data.into_iter().zip(expected_data.into_iter()) {
+    for (av, ev) in actual.data.into_iter().zip(expected_data) {
         assert_eq!(
             av.data.len(),
             ev.data.len(),
             "Lengths of inner vectors must be pairwise equal."
         );
-        for (a, e) in av.data.into_iter().zip(ev.data.into_iter()) {
+        for (a, e) in av.data.into_iter().zip(ev.data) {
             assert_eq!(a, e, "Brackets must be equal");
         }
     }
@@ -431,7 +433,7 @@ fn bracket_matrix_from_str_success() {
 #[test]
 fn bracket_try_from_char_success() {
     let s = "([{<)]}>";
-    let expected: Vec<eyre::Result<Bracket>> = vec![
+    let expected: Vec<Result<Bracket, ParseError>> = vec![
         Ok(Opening(Round)),
         Ok(Opening(Square)),
         Ok(Opening(Curly)),
@@ -441,14 +443,26 @@ fn bracket_try_from_char_success() {
         Ok(Closing(Curly)),
         Ok(Closing(Angle)),
     ];
-    let actual: Vec<eyre::Result<Bracket>> = s.chars().map(|c| Bracket::try_from(c)).collect();
+    let actual: Vec<Result<Bracket, ParseError>> = s.chars().map(Bracket::try_from).collect();
     assert_eq!(
         actual.len(),
         expected.len(),
         "Vectors need to be of the same length."
     );
-    for (a, e) in actual.into_iter().zip(expected.into_iter()) {
+    for (a, e) in actual.into_iter().zip(expected) {
         assert!(a.is_ok() && e.is_ok());
         assert_eq!(a.unwrap(), e.unwrap());
     }
 }
+
+#[test]
+fn bracket_matrix_reports_position() {
+    let actual = BracketMatrix::from_str("()\n[{x}]");
+    let expected = ParseError::UnexpectedToken {
+        token: "x".to_string(),
+        expected: "a bracket",
+        line: 2,
+        column: 3,
+    };
+    assert_eq!(actual, Err(expected));
+}
@@ -0,0 +1,132 @@
+//! Day 11: Dumbo Octopus.
+//!
+//! Simulates a grid of flashing octopuses.
+
+use aoc_common::{ParseError, Solution};
+use grid::{Grid, Pos};
+use std::{collections::HashSet, str::FromStr};
+
+/// [`Solution`] for day 11.
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = OctoGrid;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(OctoGrid::from_str(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> aoc_common::Result<Self::PartOne> {
+        Ok(solve_1(input))
+    }
+
+    fn part_two(input: &Self::Input) -> aoc_common::Result<Self::PartTwo> {
+        Ok(solve_2(input))
+    }
+}
+
+/// First step during which all octopuses flash.
+pub fn solve_2(octo_grid: &OctoGrid) -> u32 {
+    let target_flash_count = octo_grid.octopi.len() as u32;
+    octo_grid
+        .flash_counter()
+        .enumerate()
+        .find_map(|(step_count, flash_count)| {
+            if flash_count == target_flash_count {
+                Some(step_count + 1)
+            } else {
+                None
+            }
+        })
+        .unwrap_or_default() as u32
+}
+
+/// Total number of flashes during the first 100 steps.
+pub fn solve_1(octo_grid: &OctoGrid) -> u32 {
+    octo_grid.flash_counter().take(100).sum()
+}
+
+/// Iterator over the number of flashes in each step of the simulation.
+#[derive(Debug, PartialEq)]
+pub struct FlashCounter {
+    octopi: Grid<u8>,
+}
+
+impl Iterator for FlashCounter {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // 1. increase all by 1
+        for energy_level in self.octopi.iter_mut() {
+            *energy_level += 1;
+        }
+
+        // 2. flash anything over 9 and increase surrounding by 1
+        // 3. Repeat 2. until all are below 9
+        // Accumulate a running total of the number of flashes
+        // Octopi can't flash more than once in a step
+        // Tracking which octopi flashed gives us the number at the end
+        let mut flashed: HashSet<Pos> = HashSet::new();
+        while self
+            .octopi
+            .enumerate()
+            .any(|(pos, el)| *el > 9 && !flashed.contains(&pos))
+        {
+            let flash_pending = self
+                .octopi
+                .enumerate()
+                .filter(|(pos, el)| **el > 9 && !flashed.contains(pos))
+                .map(|(pos, _)| pos)
+                .collect::<HashSet<_>>();
+
+            let to_increase: Vec<Pos> = flash_pending
+                .iter()
+                .flat_map(|pos| self.octopi.neighbours8(*pos))
+                .filter(|pos| !flashed.contains(pos))
+                .collect();
+            for pos in to_increase {
+                self.octopi[pos] += 1;
+            }
+
+            flashed.extend(flash_pending);
+        }
+
+        for pos in flashed.iter() {
+            self.octopi[*pos] = 0;
+        }
+
+        Some(flashed.len() as u32)
+    }
+}
+
+/// Grid of octopus energy levels, parsed from rows of digits.
+#[derive(Debug, PartialEq)]
+pub struct OctoGrid {
+    octopi: Grid<u8>,
+}
+
+impl OctoGrid {
+    /// Starts a simulation from the current energy levels.
+    pub fn flash_counter(&self) -> FlashCounter {
+        FlashCounter {
+            octopi: self.octopi.clone(),
+        }
+    }
+}
+
+impl FromStr for OctoGrid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            octopi: Grid::parse_digits(s)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
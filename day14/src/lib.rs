@@ -0,0 +1,362 @@
+//! Day 14: Extended Polymerization.
+//!
+//! Grows a polymer by pair insertion, tracking pair counts instead of the
+//! polymer itself.
+
+use aoc_common::{parse::exact_fields, ParseError, Solution};
+use std::collections::{BTreeMap, HashMap};
+
+static SPLIT_PATTERN: &str = " -> ";
+
+/// Pair insertion rules, mapping a pair of elements to the inserted element.
+pub type Rules = HashMap<(char, char), char>;
+
+/// A polymer template and the rules it grows by.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Polymer {
+    pub template: String,
+    pub rules: Rules,
+}
+
+/// [`Solution`] for day 14.
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Polymer;
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        let mut lines = input.lines().enumerate();
+        let template = match lines.next() {
+            Some((_, template)) if !template.is_empty() => template.to_string(),
+            _ => return Err(ParseError::unexpected_end("a polymer template", 1).into()),
+        };
+        let mut rules = Rules::new();
+        for (idx, line) in lines.filter(|(_, line)| !line.is_empty()) {
+            let (pair, element) = parse_line(line).map_err(|e| e.offset(idx, 0))?;
+            rules.insert(pair, element);
+        }
+
+        Ok(Polymer { template, rules })
+    }
+
+    fn part_one(input: &Self::Input) -> aoc_common::Result<Self::PartOne> {
+        Ok(counting_solution(input, 10))
+    }
+
+    fn part_two(input: &Self::Input) -> aoc_common::Result<Self::PartTwo> {
+        Ok(counting_solution(input, 40))
+    }
+}
+
+/// Difference between the most and least common element after `iterations`
+/// steps of pair insertion.
+pub fn counting_solution(polymer: &Polymer, iterations: usize) -> u128 {
+    let letter_counts = apply_rules(bigram_counts(&polymer.template), &polymer.rules, iterations);
+    min_max_delta(&letter_counts)
+}
+
+/// Applies `rules` `n` times and returns the resulting element counts.
+pub fn apply_rules(
+    bigram_counts: BTreeMap<(char, char), u128>,
+    rules: &Rules,
+    n: usize,
+) -> BTreeMap<char, u128> {
+    let mut bigram_counts = bigram_counts;
+    for _ in 0..n {
+        let new_bigram_counts = bigram_counts
+            .iter()
+            .fold(BTreeMap::new(), |mut acc, (k, &v)| {
+                if let Some(&c) = rules.get(k) {
+                    *(acc.entry((k.0, c)).or_default()) += v;
+                    *(acc.entry((c, k.1)).or_default()) += v;
+                } else {
+                    *(acc.entry(*k).or_default()) += v;
+                }
+                acc
+            });
+        bigram_counts = new_bigram_counts;
+    }
+
+    let mut letter_counts = bigram_counts
+        .iter()
+        .fold(BTreeMap::new(), |mut acc, ((f, s), &v)| {
+            *(acc.entry(*f).or_default()) += v;
+            *(acc.entry(*s).or_default()) += v;
+            acc
+        });
+    for (_, v) in letter_counts.iter_mut() {
+        *v = (*v / 2) + (*v % 2);
+    }
+
+    letter_counts
+}
+
+/// Counts the pairs of adjacent elements in `s`.
+pub fn bigram_counts(s: &str) -> BTreeMap<(char, char), u128> {
+    let mut btree = BTreeMap::new();
+    let s = s.chars().collect::<Vec<char>>();
+    let s = s.as_slice();
+    for n in 0..s.len() - 1 {
+        let k = (s[n], s[n + 1]);
+        let old_value = btree.entry(k).or_default();
+        *old_value += 1;
+    }
+
+    btree
+}
+
+/// Parses a single `AB -> C` rule.
+pub fn parse_line(line: &str) -> Result<((char, char), char), ParseError> {
+    let [(pair_column, pair), (element_column, element)] = exact_fields(line, SPLIT_PATTERN)?;
+    let [first, second] = elements(pair, pair_column, "a pair of elements", "` -> `")?;
+    let [inserted] = elements(element, element_column, "an element", "end of line")?;
+    Ok(((first, second), inserted))
+}
+
+/// Reads exactly `N` elements from `field`, which starts at `column`, and
+/// expects `then` after them.
+fn elements<const N: usize>(
+    field: &str,
+    column: usize,
+    expected: &'static str,
+    then: &'static str,
+) -> Result<[char; N], ParseError> {
+    let mut chars = field.char_indices();
+    let mut elements = ['\0'; N];
+    for element in &mut elements {
+        *element = match chars.next() {
+            Some((_, c)) => c,
+            None => return Err(ParseError::unexpected_end(expected, column + field.len())),
+        };
+    }
+    match chars.next() {
+        Some((idx, c)) => Err(ParseError::unexpected_token(c, then, column + idx)),
+        None => Ok(elements),
+    }
+}
+
+/// Difference between the largest and smallest count.
+pub fn min_max_delta(btree: &BTreeMap<char, u128>) -> u128 {
+    btree
+        .values()
+        .min()
+        .zip(btree.values().max())
+        .map(|(min, max)| max - min)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{BTreeMap, HashMap},
+        fs,
+    };
+
+    use aoc_common::{ErrorKind, ParseError, Solution};
+
+    use super::{
+        apply_rules, bigram_counts, counting_solution, min_max_delta, parse_line, Day14, Polymer,
+    };
+    #[test]
+    fn parse_line_rejects_malformed_rules() {
+        let column = |line| parse_line(line).unwrap_err().column();
+        assert_eq!(column("AB => C"), 8);
+        assert_eq!(column("AB: C"), 6);
+        assert_eq!(column("AB C"), 5);
+        assert_eq!(column(""), 1);
+        assert_eq!(
+            parse_line("A -> C"),
+            Err(ParseError::unexpected_end("a pair of elements", 2))
+        );
+        assert_eq!(
+            parse_line("ABC -> D"),
+            Err(ParseError::unexpected_token('C', "` -> `", 3))
+        );
+        assert_eq!(
+            parse_line("AB -> "),
+            Err(ParseError::unexpected_end("an element", 7))
+        );
+        assert_eq!(
+            parse_line("AB -> CD"),
+            Err(ParseError::unexpected_token('D', "end of line", 8))
+        );
+    }
+
+    #[test]
+    fn parse_line_accepts_rules() {
+        let lines = vec![
+            "CH -> B", "HH -> N", "CB -> H", "NH -> C", "HB -> C", "HC -> B", "HN -> C", "NN -> C",
+            "BH -> H", "NC -> B", "NB -> B", "BN -> B", "BB -> N", "BC -> B", "CC -> N", "CN -> C",
+        ];
+        let actual: Vec<_> = lines
+            .into_iter()
+            .map(|line| parse_line(line).ok())
+            .collect();
+        let expected = vec![
+            Some((('C', 'H'), 'B')),
+            Some((('H', 'H'), 'N')),
+            Some((('C', 'B'), 'H')),
+            Some((('N', 'H'), 'C')),
+            Some((('H', 'B'), 'C')),
+            Some((('H', 'C'), 'B')),
+            Some((('H', 'N'), 'C')),
+            Some((('N', 'N'), 'C')),
+            Some((('B', 'H'), 'H')),
+            Some((('N', 'C'), 'B')),
+            Some((('N', 'B'), 'B')),
+            Some((('B', 'N'), 'B')),
+            Some((('B', 'B'), 'N')),
+            Some((('B', 'C'), 'B')),
+            Some((('C', 'C'), 'N')),
+            Some((('C', 'N'), 'C')),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn parse_small_input() {
+        let content = fs::read_to_string("small_input.txt").unwrap();
+        let actual = Day14::parse(content.as_str()).unwrap();
+        let expected = Polymer {
+            template: "NNCB".to_string(),
+            rules: HashMap::from([
+                (('C', 'H'), 'B'),
+                (('H', 'H'), 'N'),
+                (('C', 'B'), 'H'),
+                (('N', 'H'), 'C'),
+                (('H', 'B'), 'C'),
+                (('H', 'C'), 'B'),
+                (('H', 'N'), 'C'),
+                (('N', 'N'), 'C'),
+                (('B', 'H'), 'H'),
+                (('N', 'C'), 'B'),
+                (('N', 'B'), 'B'),
+                (('B', 'N'), 'B'),
+                (('B', 'B'), 'N'),
+                (('B', 'C'), 'B'),
+                (('C', 'C'), 'N'),
+                (('C', 'N'), 'C'),
+            ]),
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn bigram_counts_works() {
+        let input = "NBBNBNBBCCNBCNCCNBBNBBNBBBNBBNBBCBHCBHHNHCBBCBHCB";
+        let actual = bigram_counts(input);
+        let expected = BTreeMap::from([
+            (('N', 'B'), 9),
+            (('B', 'B'), 9),
+            (('B', 'N'), 6),
+            (('B', 'C'), 4),
+            (('C', 'C'), 2),
+            (('C', 'N'), 3),
+            (('N', 'C'), 1),
+            (('C', 'B'), 5),
+            (('B', 'H'), 3),
+            (('H', 'C'), 3),
+            (('H', 'H'), 1),
+            (('H', 'N'), 1),
+            (('N', 'H'), 1),
+        ]);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn apply_rules_works() {
+        let initial = BTreeMap::from([
+            // NNCB
+            (('N', 'N'), 1),
+            (('N', 'C'), 1),
+            (('C', 'B'), 1),
+        ]);
+        let rules = HashMap::from([
+            (('C', 'H'), 'B'),
+            (('H', 'H'), 'N'),
+            (('C', 'B'), 'H'),
+            (('N', 'H'), 'C'),
+            (('H', 'B'), 'C'),
+            (('H', 'C'), 'B'),
+            (('H', 'N'), 'C'),
+            (('N', 'N'), 'C'),
+            (('B', 'H'), 'H'),
+            (('N', 'C'), 'B'),
+            (('N', 'B'), 'B'),
+            (('B', 'N'), 'B'),
+            (('B', 'B'), 'N'),
+            (('B', 'C'), 'B'),
+            (('C', 'C'), 'N'),
+            (('C', 'N'), 'C'),
+        ]);
+        let actual = apply_rules(initial, &rules, 4);
+        // let expected_bigram_counts = BTreeMap::from([
+        //     (('N', 'B'), 9),
+        //     (('B', 'B'), 9),
+        //     (('B', 'N'), 6),
+        //     (('B', 'C'), 4),
+        //     (('C', 'C'), 2),
+        //     (('C', 'N'), 3),
+        //     (('N', 'C'), 1),
+        //     (('C', 'B'), 5),
+        //     (('B', 'H'), 3),
+        //     (('H', 'C'), 3),
+        //     (('H', 'H'), 1),
+        //     (('H', 'N'), 1),
+        //     (('N', 'H'), 1),
+        // ]);
+        let expected_letter_counts = BTreeMap::from([('N', 11), ('B', 23), ('C', 10), ('H', 5)]);
+        // let expected = (expected_bigram_counts, expected_letter_counts);
+        assert_eq!(actual, expected_letter_counts);
+    }
+
+    #[test]
+    fn min_max_delta_works() {
+        let input = BTreeMap::from([('N', 11), ('B', 23), ('C', 10), ('H', 5)]);
+        let actual = min_max_delta(&input);
+        let expected = 23 - 5;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn small_input() {
+        let content = fs::read_to_string("small_input.txt").unwrap();
+        let polymer = Day14::parse(content.as_str()).unwrap();
+        let actual = counting_solution(&polymer, 4);
+        let expected = 23 - 5;
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_reports_invalid_rule() {
+        let error = Day14::parse("NNCB\n\nCH -> B\nHH => N").unwrap_err();
+        let expected = ParseError::WrongFieldCount {
+            expected: 2,
+            found: 1,
+            line: 4,
+            column: 8,
+        };
+        assert!(matches!(error.kind(), ErrorKind::Parse(e) if *e == expected));
+
+        let error = Day14::parse("NNCB\n\nCH -> B\nHH -> NN").unwrap_err();
+        let expected = ParseError::UnexpectedToken {
+            token: "N".to_string(),
+            expected: "end of line",
+            line: 4,
+            column: 8,
+        };
+        assert!(matches!(error.kind(), ErrorKind::Parse(e) if *e == expected));
+    }
+
+    #[test]
+    fn single_element_templates_have_no_spread() {
+        let polymer = Day14::parse("N\n\nNN -> C").unwrap();
+        assert_eq!(counting_solution(&polymer, 10), 0);
+        assert!(Day14::parse("").is_err());
+    }
+}
@@ -0,0 +1,193 @@
+//! Day 25: Sea Cucumber.
+//!
+//! Moves two herds of sea cucumbers until they are stuck.
+
+use aoc_common::{Error, ParseError, Solution};
+use grid::{Grid, Pos};
+use std::{
+    convert::Infallible,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// [`Solution`] for day 25.
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const HAS_PART_TWO: bool = false;
+
+    type Input = Herds;
+    type PartOne = u32;
+    type PartTwo = Infallible;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(Herds::from_str(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> aoc_common::Result<Self::PartOne> {
+        Ok(herd_deadlock(&mut input.clone()))
+    }
+
+    fn part_two(_: &Self::Input) -> aoc_common::Result<Self::PartTwo> {
+        Err(Error::unsolvable("Day 25 has no part two."))
+    }
+}
+
+/// First step on which no sea cucumber moves.
+pub fn herd_deadlock(herds: &mut Herds) -> u32 {
+    let mut previous_positions = herds.next();
+    let mut current_positions = herds.next();
+    let mut counter = 1;
+    // Iterate while counting until previous and current are the same.
+    while previous_positions != current_positions {
+        counter += 1;
+        previous_positions = current_positions;
+        current_positions = herds.next();
+    }
+
+    counter
+}
+
+/// `(x, y)` positions of the east-facing (`>`) and south-facing (`v`) herds.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CucumberPositions {
+    pub east: Vec<(u8, u8)>,
+    pub south: Vec<(u8, u8)>,
+}
+
+impl From<&str> for CucumberPositions {
+    fn from(s: &str) -> Self {
+        let (east, south) = s
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.char_indices()
+                    .map(|(x, ch)| (ch, (x as u8, y as u8)))
+                    .collect::<Vec<(char, (u8, u8))>>()
+            })
+            .fold(
+                (Vec::new(), Vec::new()),
+                |(mut eacc, mut sacc), (ch, pos)| {
+                    match ch {
+                        '>' => {
+                            eacc.push(pos);
+                        }
+                        'v' => {
+                            sacc.push(pos);
+                        }
+                        _ => {}
+                    }
+
+                    (eacc, sacc)
+                },
+            );
+
+        Self { east, south }
+    }
+}
+
+/// A cell of the sea floor.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Tile {
+    Empty,
+    East,
+    South,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Tile::Empty),
+            '>' => Some(Tile::East),
+            'v' => Some(Tile::South),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Tile::Empty => '.',
+            Tile::East => '>',
+            Tile::South => 'v',
+        };
+        write!(f, "{c}")
+    }
+}
+
+/// Both herds on a map that wraps around at its edges.
+///
+/// Iterating moves both herds by one step and yields their positions before
+/// the step.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Herds {
+    floor: Grid<Tile>,
+}
+
+impl Herds {
+    pub fn positions(&self) -> CucumberPositions {
+        let (mut east, mut south) = (Vec::new(), Vec::new());
+        for ((x, y), tile) in self.floor.enumerate() {
+            match tile {
+                Tile::East => east.push((x as u8, y as u8)),
+                Tile::South => south.push((x as u8, y as u8)),
+                Tile::Empty => {}
+            }
+        }
+
+        CucumberPositions { east, south }
+    }
+
+    /// Moves every cucumber of `herd` whose destination is free.
+    fn step(&mut self, herd: Tile, direction: (isize, isize)) {
+        // All cucumbers of a herd look at the floor before any of them moves.
+        let moves: Vec<(Pos, Pos)> = self
+            .floor
+            .enumerate()
+            .filter(|(_, tile)| **tile == herd)
+            .filter_map(|(pos, _)| {
+                let dst = self.floor.step(pos, direction)?;
+                (self.floor[dst] == Tile::Empty).then_some((pos, dst))
+            })
+            .collect();
+
+        for (src, dst) in moves {
+            self.floor[src] = Tile::Empty;
+            self.floor[dst] = herd;
+        }
+    }
+}
+
+impl Iterator for Herds {
+    type Item = CucumberPositions;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let positions = self.positions();
+        self.step(Tile::East, (1, 0));
+        self.step(Tile::South, (0, 1));
+
+        Some(positions)
+    }
+}
+
+impl FromStr for Herds {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let floor = Grid::parse(s, "`>`, `v` or `.`", Tile::from_char)?;
+        Ok(Self {
+            floor: floor.toroidal(),
+        })
+    }
+}
+
+impl Display for Herds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.floor)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -7,7 +7,7 @@ use crate::{herd_deadlock, CucumberPositions, Herds};
 fn part_one() {
     let expected = 482;
     let s = fs::read_to_string("input.txt").unwrap();
-    let mut herds = Herds::from(s.as_str());
+    let mut herds: Herds = s.parse().unwrap();
     let actual = herd_deadlock(&mut herds);
     assert_eq!(actual, expected);
 }
@@ -79,70 +79,67 @@ fn small_input_first_5_steps() {
 
 #[test]
 fn parse_small_input_works() {
-    let expected = Herds {
-        cucumbers: CucumberPositions {
-            east: vec![
-                (4, 0),
-                (5, 0),
-                (9, 0),
-                (3, 1),
-                (4, 1),
-                (0, 2),
-                (1, 2),
-                (3, 2),
-                (5, 2),
-                (0, 3),
-                (1, 3),
-                (3, 3),
-                (4, 3),
-                (6, 3),
-                (1, 4),
-                (0, 5),
-                (2, 5),
-                (3, 5),
-                (5, 6),
-                (7, 6),
-                (5, 7),
-                (6, 7),
-                (9, 8),
-            ],
-            south: vec![
-                (0, 0),
-                (7, 0),
-                (8, 0),
-                (1, 1),
-                (2, 1),
-                (6, 1),
-                (7, 1),
-                (4, 2),
-                (9, 2),
-                (2, 3),
-                (8, 3),
-                (0, 4),
-                (2, 4),
-                (4, 4),
-                (5, 4),
-                (7, 4),
-                (6, 5),
-                (1, 6),
-                (2, 6),
-                (8, 6),
-                (0, 7),
-                (2, 7),
-                (7, 7),
-                (9, 7),
-                (4, 8),
-                (7, 8),
-            ],
-        },
-        max_x: 9,
-        max_y: 8,
+    let expected = CucumberPositions {
+        east: vec![
+            (4, 0),
+            (5, 0),
+            (9, 0),
+            (3, 1),
+            (4, 1),
+            (0, 2),
+            (1, 2),
+            (3, 2),
+            (5, 2),
+            (0, 3),
+            (1, 3),
+            (3, 3),
+            (4, 3),
+            (6, 3),
+            (1, 4),
+            (0, 5),
+            (2, 5),
+            (3, 5),
+            (5, 6),
+            (7, 6),
+            (5, 7),
+            (6, 7),
+            (9, 8),
+        ],
+        south: vec![
+            (0, 0),
+            (7, 0),
+            (8, 0),
+            (1, 1),
+            (2, 1),
+            (6, 1),
+            (7, 1),
+            (4, 2),
+            (9, 2),
+            (2, 3),
+            (8, 3),
+            (0, 4),
+            (2, 4),
+            (4, 4),
+            (5, 4),
+            (7, 4),
+            (6, 5),
+            (1, 6),
+            (2, 6),
+            (8, 6),
+            (0, 7),
+            (2, 7),
+            (7, 7),
+            (9, 7),
+            (4, 8),
+            (7, 8),
+        ],
     };
-    let s = small_input();
-    let actual = Herds::from(s.as_str());
-    assert_eq!(actual.cucumbers, expected.cucumbers, "cucumbers differ");
-    assert_eq!(actual.max_x, expected.max_x, "widths differ");
-    assert_eq!(actual.max_y, expected.max_y, "heights differ");
+    let actual = small_herds();
+    assert_eq!(actual.positions(), expected, "cucumbers differ");
+    assert_eq!(actual.floor.width(), 10, "widths differ");
+    assert_eq!(actual.floor.height(), 9, "heights differ");
+    assert!(actual.floor.is_toroidal());
+    assert_eq!(actual.to_string(), small_input());
 }
 
 fn small_input() -> String {
@@ -150,5 +147,5 @@ fn small_input() -> String {
 }
 
 fn small_herds() -> Herds {
-    Herds::try_from(small_input().as_str()).unwrap()
+    small_input().parse().unwrap()
 }
@@ -0,0 +1,345 @@
+//! A rectangular two dimensional grid for the days that work on maps.
+
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use aoc_common::ParseError;
+
+/// An `(x, y)` position, `x` grows to the right and `y` downwards.
+pub type Pos = (usize, usize);
+
+/// Offsets of the horizontal and vertical neighbours, in reading order.
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of all eight neighbours, in reading order.
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+///
+/// A toroidal grid wraps around at its edges, so every cell has a full set of
+/// neighbours.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    toroidal: bool,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// If there are not exactly `width * height` cells.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "cells do not fill the grid");
+        Self {
+            cells,
+            width,
+            height,
+            toroidal: false,
+        }
+    }
+
+    /// Parses one row per line, converting every character with `f`.
+    ///
+    /// Characters `f` rejects are reported as unexpected tokens, `expected`
+    /// describes what `f` accepts.
+    pub fn parse(
+        s: &str,
+        expected: &'static str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in s.lines().enumerate() {
+            let mut found = 0;
+            for (x, c) in line.chars().enumerate() {
+                let cell = f(c).ok_or_else(|| ParseError::UnexpectedToken {
+                    token: c.to_string(),
+                    expected,
+                    line: y + 1,
+                    column: x + 1,
+                })?;
+                cells.push(cell);
+                found += 1;
+            }
+
+            let width = *width.get_or_insert(found);
+            if found != width {
+                return Err(ParseError::WrongFieldCount {
+                    expected: width,
+                    found,
+                    line: y + 1,
+                    column: found.min(width) + 1,
+                });
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self::new(width, height, cells)),
+            _ => Err(ParseError::unexpected_end("a grid row", 1)),
+        }
+    }
+
+    /// Makes the grid wrap around at its edges.
+    pub fn toroidal(mut self) -> Self {
+        self.toroidal = true;
+        self
+    }
+
+    pub fn is_toroidal(&self) -> bool {
+        self.toroidal
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|idx| &mut self.cells[idx])
+    }
+
+    /// Position one step of `(dx, dy)` away from `pos`.
+    ///
+    /// Returns `None` if that leaves a grid that is not toroidal.
+    pub fn step(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        if self.toroidal {
+            let x = (x as isize + dx).rem_euclid(self.width as isize) as usize;
+            let y = (y as isize + dy).rem_euclid(self.height as isize) as usize;
+            Some((x, y))
+        } else {
+            let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            self.contains(pos).then_some(pos)
+        }
+    }
+
+    /// The horizontal and vertical neighbours of `pos`.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&offset| self.step(pos, offset))
+    }
+
+    /// All eight neighbours of `pos`, including the diagonal ones.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        SURROUNDING
+            .iter()
+            .filter_map(move |&offset| self.step(pos, offset))
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let (width, height) = (self.width, self.height);
+        (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// All cells in row-major order, together with their position.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The cells of row `y`.
+    ///
+    /// # Panics
+    ///
+    /// If `y` is outside the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} is outside the grid");
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// A grid of the same shape with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+            toroidal: self.toroidal,
+        }
+    }
+
+    fn index_of(&self, (x, y): Pos) -> Option<usize> {
+        self.contains((x, y)).then(|| x + y * self.width)
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a map of single digits.
+    pub fn parse_digits(s: &str) -> Result<Self, ParseError> {
+        Self::parse(s, "a digit", |c| c.to_digit(10).map(|d| d as u8))
+    }
+}
+
+impl Grid<char> {
+    /// Parses a map of arbitrary characters.
+    pub fn parse_chars(s: &str) -> Result<Self, ParseError> {
+        Self::parse(s, "a character", Some)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos).expect("position is outside the grid")
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        self.get_mut(pos).expect("position is outside the grid")
+    }
+}
+
+/// Prints one line per row, without separators between cells.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::ParseError;
+
+    use super::Grid;
+
+    fn small() -> Grid<u8> {
+        Grid::parse_digits("123\n456").unwrap()
+    }
+
+    #[test]
+    fn parse_digits_works() {
+        let actual = small();
+        assert_eq!(actual, Grid::new(3, 2, vec![1, 2, 3, 4, 5, 6]));
+        assert_eq!(actual[(2, 1)], 6);
+        assert_eq!(actual.get((3, 0)), None);
+    }
+
+    #[test]
+    fn parse_reports_position() {
+        let expected = ParseError::UnexpectedToken {
+            token: "x".to_string(),
+            expected: "a digit",
+            line: 2,
+            column: 2,
+        };
+        assert_eq!(Grid::parse_digits("123\n4x6"), Err(expected));
+
+        let expected = ParseError::WrongFieldCount {
+            expected: 3,
+            found: 2,
+            line: 2,
+            column: 3,
+        };
+        assert_eq!(Grid::parse_digits("123\n45"), Err(expected));
+        assert!(Grid::parse_chars("").is_err());
+    }
+
+    #[test]
+    fn neighbours_of_corner() {
+        let grid = small();
+        let actual: Vec<_> = grid.neighbours4((0, 0)).collect();
+        assert_eq!(actual, vec![(1, 0), (0, 1)]);
+        let actual: Vec<_> = grid.neighbours8((0, 0)).collect();
+        assert_eq!(actual, vec![(1, 0), (0, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn toroidal_neighbours_wrap() {
+        let grid = Grid::parse_digits("123\n456\n789").unwrap().toroidal();
+        let actual: Vec<_> = grid.neighbours4((0, 0)).collect();
+        assert_eq!(actual, vec![(0, 2), (2, 0), (1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours8((0, 0)).count(), 8);
+        assert_eq!(grid.step((2, 2), (1, 1)), Some((0, 0)));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = small();
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn display_prints_rows() {
+        let grid = Grid::parse_chars(".>v\nv..").unwrap();
+        assert_eq!(grid.to_string(), ".>v\nv..");
+        assert_eq!(small().map(|n| n * 2).to_string(), "246\n81012");
+    }
+}